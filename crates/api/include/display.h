@@ -0,0 +1,24 @@
+#ifndef DISPLAY_H
+#define DISPLAY_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "lvgl/lvgl.h"
+
+/// The callbacks of the display, with the arguments of the lvgl display
+/// driver. `flush` must call `lv_flush_ready` when finished, which may also
+/// happen later, e.g. from a DMA interrupt. `fill` and `map` may be null.
+typedef struct DisplayCallbacks {
+    void (*flush)(int32_t x1, int32_t y1, int32_t x2, int32_t y2, const lv_color_t * color_p);
+    void (*fill)(int32_t x1, int32_t y1, int32_t x2, int32_t y2, lv_color_t color);
+    void (*map)(int32_t x1, int32_t y1, int32_t x2, int32_t y2, const lv_color_t * color_p);
+} DisplayCallbacks;
+
+/// Called by system at start after the lvgl library has been initialized, to
+/// register the display through the lvgl crate. The callbacks are copied.
+/// Only the first call registers the display, returns false if there is not
+/// enough memory or the display is already registered.
+extern bool display_register(const DisplayCallbacks * callbacks);
+
+#endif // DISPLAY_H
@@ -0,0 +1,120 @@
+use crate::simulator::{Simulator, HEIGHT, WIDTH};
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+
+/// Set this environment variable to rewrite the golden images with the
+/// current frames instead of comparing against them.
+pub const BLESS_ENV: &str = "HEADLESS_BLESS";
+
+/// Color of the pixels which differ from the golden image in a diff image.
+const DIFF_COLOR: [u8; 3] = [0xFF, 0x00, 0xFF];
+
+/// Compare the current frame against the golden image `tests/golden/<name>.png`.
+///
+/// On a mismatch the actual frame and a diff image, which highlights the
+/// differing pixels, are written to `tests/golden/failures` and the calling
+/// test fails.
+pub fn assert_golden(sim: &mut Simulator, name: &str) {
+    sim.refresh();
+    let actual = to_rgb(sim.framebuffer());
+    let golden_path = golden_dir().join(format!("{}.png", name));
+
+    if env::var_os(BLESS_ENV).is_some() {
+        fs::create_dir_all(golden_dir()).expect("Failed to create the golden directory");
+        write_png(&golden_path, &actual).expect("Failed to write the golden image");
+        return;
+    }
+
+    let expected = match read_png(&golden_path) {
+        Ok(expected) => expected,
+        Err(err) => panic!(
+            "Failed to read golden image {}: {} (run with {}=1 to create it)",
+            golden_path.display(),
+            err,
+            BLESS_ENV
+        ),
+    };
+
+    let mut diff = Vec::with_capacity(actual.len());
+    let mut mismatches = 0;
+    for (a, e) in actual.chunks(3).zip(expected.chunks(3)) {
+        if a == e {
+            // Dim the matching pixels so the differences stand out.
+            diff.extend(e.iter().map(|c| c / 4));
+        } else {
+            diff.extend_from_slice(&DIFF_COLOR);
+            mismatches += 1;
+        }
+    }
+    if mismatches == 0 {
+        return;
+    }
+
+    let failures_dir = golden_dir().join("failures");
+    let actual_path = failures_dir.join(format!("{}.actual.png", name));
+    let diff_path = failures_dir.join(format!("{}.diff.png", name));
+    fs::create_dir_all(&failures_dir).expect("Failed to create the failures directory");
+    write_png(&actual_path, &actual).expect("Failed to write the actual image");
+    write_png(&diff_path, &diff).expect("Failed to write the diff image");
+    panic!(
+        "Frame differs from golden image {} in {} pixels, see {} (run with {}=1 to accept it)",
+        golden_path.display(),
+        mismatches,
+        diff_path.display(),
+        BLESS_ENV
+    );
+}
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+/// Convert ARGB8888 pixels to packed RGB888.
+fn to_rgb(pixels: &[u32]) -> Vec<u8> {
+    let mut rgb = Vec::with_capacity(pixels.len() * 3);
+    for pixel in pixels {
+        rgb.push((pixel >> 16) as u8);
+        rgb.push((pixel >> 8) as u8);
+        rgb.push(*pixel as u8);
+    }
+    rgb
+}
+
+fn write_png(path: &Path, rgb: &[u8]) -> io::Result<()> {
+    use png::HasParameters;
+
+    let file = File::create(path)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), WIDTH as u32, HEIGHT as u32);
+    encoder.set(png::ColorType::RGB).set(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgb)?;
+    Ok(())
+}
+
+fn read_png(path: &Path) -> io::Result<Vec<u8>> {
+    let decoder = png::Decoder::new(File::open(path)?);
+    let (info, mut reader) = decoder.read_info()?;
+    if info.width as usize != WIDTH || info.height as usize != HEIGHT {
+        let msg = format!("unexpected size {}x{}", info.width, info.height);
+        return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+    }
+
+    let mut buffer = vec![0; info.buffer_size()];
+    reader.next_frame(&mut buffer)?;
+    match (info.color_type, info.bit_depth) {
+        (png::ColorType::RGB, png::BitDepth::Eight) => Ok(buffer),
+        (png::ColorType::RGBA, png::BitDepth::Eight) => Ok(buffer
+            .chunks(4)
+            .flat_map(|rgba| rgba[..3].iter().cloned())
+            .collect()),
+        _ => {
+            let msg = format!("unsupported format {:?}", info.color_type);
+            Err(io::Error::new(io::ErrorKind::InvalidData, msg))
+        }
+    }
+}
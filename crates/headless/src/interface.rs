@@ -0,0 +1,24 @@
+#![allow(non_upper_case_globals)]
+
+use api::*;
+
+use crate::simulator::{BUTTON_PRESSED, LED_GREEN, LED_RED};
+
+use std::sync::atomic::Ordering;
+
+#[no_mangle]
+pub extern "C" fn drivers_init() {}
+
+#[no_mangle]
+pub extern "C" fn drivers_set_led(which: LEDs, on: bool) {
+    match which {
+        LEDs_GREEN => LED_GREEN.store(on, Ordering::SeqCst),
+        LEDs_RED => LED_RED.store(on, Ordering::SeqCst),
+        _ => unreachable!(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn drivers_is_button_pressed() -> bool {
+    BUTTON_PRESSED.load(Ordering::SeqCst)
+}
@@ -0,0 +1,10 @@
+//! Headless host simulator which renders the HMI into an in-memory
+//! framebuffer, so the whole UI can be exercised with `cargo test`.
+
+mod golden;
+mod interface;
+mod simulator;
+
+pub use self::golden::*;
+pub use self::interface::*;
+pub use self::simulator::*;
@@ -0,0 +1,251 @@
+#![allow(non_upper_case_globals)]
+
+use api::*;
+use hmi::HmiManager;
+use lvgl::*;
+use lvgl_sys::*;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, Once};
+
+/// Horizontal resolution of the simulated display.
+pub const WIDTH: usize = LV_HOR_RES as usize;
+
+/// Vertical resolution of the simulated display.
+pub const HEIGHT: usize = LV_VER_RES as usize;
+
+/// Period of the virtual clock in milliseconds, matching the delay of the
+/// device main loop.
+pub const STEP_MS: u32 = 10;
+
+/// Duration of a short press of the user button.
+const CLICK_MS: u32 = 100;
+
+pub(crate) static BUTTON_PRESSED: AtomicBool = AtomicBool::new(false);
+pub(crate) static LED_GREEN: AtomicBool = AtomicBool::new(false);
+pub(crate) static LED_RED: AtomicBool = AtomicBool::new(false);
+
+// Only accessed from the display callbacks and while holding the lock.
+static mut FRAMEBUFFER: [u32; WIDTH * HEIGHT] = [0; WIDTH * HEIGHT];
+
+/// A simulator session with exclusive access to the lvgl library.
+///
+/// lvgl keeps its state in globals, so sessions are serialized: creating a
+/// new session blocks until the previous one has been dropped.
+pub struct Simulator {
+    hmi: HmiManager,
+    elapsed: u32,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Simulator {
+    /// Start a new session with a freshly initialized HMI on a new screen.
+    pub fn new() -> Self {
+        let lock = lock();
+
+        unsafe {
+            for pixel in FRAMEBUFFER.iter_mut() {
+                *pixel = 0;
+            }
+        }
+        BUTTON_PRESSED.store(false, Ordering::SeqCst);
+        LED_GREEN.store(false, Ordering::SeqCst);
+        LED_RED.store(false, Ordering::SeqCst);
+
+        let previous = unsafe { lv_scr_act() };
+        let mut hmi = HmiManager::new_empty();
+        hmi.init();
+        unsafe {
+            // The HMI leaks its screen. The manager of the previous session,
+            // which had the only handles to it, has been dropped.
+            if lv_scr_act() != previous {
+                lv_obj_del(previous);
+            }
+            lv_obj_invalidate(lv_scr_act());
+        }
+
+        let mut simulator = Simulator {
+            hmi,
+            elapsed: 0,
+            _lock: lock,
+        };
+        simulator.step();
+        simulator
+    }
+
+    /// Run a single iteration of the application loop and advance the virtual
+    /// clock by `STEP_MS`.
+    pub fn step(&mut self) {
+        unsafe {
+            self.hmi.update();
+        }
+        Tick::inc(STEP_MS);
+        unsafe {
+            lv_task_handler();
+        }
+        self.elapsed += STEP_MS;
+    }
+
+    /// Run the application loop for at least `ms` milliseconds of virtual time.
+    pub fn advance(&mut self, ms: u32) {
+        let steps = (ms + STEP_MS - 1) / STEP_MS;
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Get the virtual time elapsed since the session started.
+    pub fn elapsed(&self) -> u32 {
+        self.elapsed
+    }
+
+    /// Set the state of the simulated user button.
+    pub fn set_button(&mut self, pressed: bool) {
+        BUTTON_PRESSED.store(pressed, Ordering::SeqCst);
+    }
+
+    /// Press the user button, hold it for `ms` milliseconds and release it.
+    pub fn press_button(&mut self, ms: u32) {
+        self.set_button(true);
+        self.advance(ms);
+        self.set_button(false);
+        self.step();
+    }
+
+    /// Press and release the user button quickly.
+    pub fn click_button(&mut self) {
+        self.press_button(CLICK_MS);
+    }
+
+    /// Get the screen of the HMI, to create more objects on.
+    pub fn screen(&self) -> &'static Screen {
+        self.hmi.screen().expect("HMI screen not created")
+    }
+
+    /// Get the input device of the user button, registered as an encoder by
+    /// the HMI.
+    pub fn user_button(&self) -> Indev {
+        self.hmi.user_button().expect("User button not registered")
+    }
+
+    /// Get the current state of an LED.
+    pub fn led(&self, which: LEDs) -> bool {
+        match which {
+            LEDs_GREEN => LED_GREEN.load(Ordering::SeqCst),
+            LEDs_RED => LED_RED.load(Ordering::SeqCst),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Redraw all invalidated areas immediately instead of waiting for the
+    /// next refresh period.
+    pub fn refresh(&mut self) {
+        unsafe {
+            lv_refr_now();
+        }
+    }
+
+    /// Get the contents of the framebuffer as ARGB8888 pixels in row order.
+    pub fn framebuffer(&self) -> &[u32] {
+        unsafe { &FRAMEBUFFER }
+    }
+
+    /// Get a single ARGB8888 pixel of the framebuffer.
+    pub fn pixel(&self, x: usize, y: usize) -> u32 {
+        self.framebuffer()[y * WIDTH + x]
+    }
+}
+
+fn lock() -> MutexGuard<'static, ()> {
+    static INIT: Once = Once::new();
+    static mut LOCK: Option<Mutex<()>> = None;
+
+    unsafe {
+        INIT.call_once(|| {
+            LOCK = Some(Mutex::new(()));
+            init_lvgl();
+        });
+
+        // A failed test must not take down the sessions which follow it.
+        match LOCK.as_ref().unwrap().lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+unsafe fn init_lvgl() {
+    static mut DISPLAY: FramebufferDisplay = FramebufferDisplay;
+
+    // Initialize the lvgl library.
+    lv_init();
+
+    // Register the display driver.
+    Display::register(&mut DISPLAY);
+}
+
+/// Display driver rendering into `FRAMEBUFFER`.
+struct FramebufferDisplay;
+
+impl DisplayDriver for FramebufferDisplay {
+    fn flush(&mut self, area: Area, colors: &[Color]) {
+        copy_colors(area, colors);
+
+        // Let lvgl know we have finished flushing the buffer.
+        Display::flush_ready();
+    }
+
+    fn fill(&mut self, area: Area, color: Color) {
+        let (cx1, cy1, cx2, cy2) = match clip(area) {
+            Some(clipped) => clipped,
+            None => return,
+        };
+        let color = color.to_u32();
+        for y in cy1..=cy2 {
+            for x in cx1..=cx2 {
+                unsafe {
+                    FRAMEBUFFER[y * WIDTH + x] = color;
+                }
+            }
+        }
+    }
+
+    fn map(&mut self, area: Area, colors: &[Color]) {
+        copy_colors(area, colors);
+    }
+}
+
+/// Clip an area to the display, returning `None` if nothing is visible.
+fn clip(area: Area) -> Option<(usize, usize, usize, usize)> {
+    let Area { x1, y1, x2, y2 } = area;
+    if x2 < 0 || y2 < 0 || x1 >= WIDTH as i32 || y1 >= HEIGHT as i32 || area.is_empty() {
+        return None;
+    }
+    let cx1 = x1.max(0) as usize;
+    let cy1 = y1.max(0) as usize;
+    let cx2 = x2.min(WIDTH as i32 - 1) as usize;
+    let cy2 = y2.min(HEIGHT as i32 - 1) as usize;
+    Some((cx1, cy1, cx2, cy2))
+}
+
+fn copy_colors(area: Area, colors: &[Color]) {
+    if colors.is_empty() {
+        return;
+    }
+    let (cx1, cy1, cx2, cy2) = match clip(area) {
+        Some(clipped) => clipped,
+        None => return,
+    };
+
+    // The colors always cover the unclipped area.
+    let src_width = area.width() as usize;
+    for y in cy1..=cy2 {
+        let src_row = (y as i32 - area.y1) as usize * src_width;
+        for x in cx1..=cx2 {
+            let color = colors[src_row + (x as i32 - area.x1) as usize];
+            unsafe {
+                FRAMEBUFFER[y * WIDTH + x] = color.to_u32();
+            }
+        }
+    }
+}
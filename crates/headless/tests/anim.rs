@@ -0,0 +1,39 @@
+use headless::*;
+use lvgl::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ANIMATED_STYLE: Lazy<Style> =
+    Lazy::new(|| Style::build(|style| style.copy_from(Style::plain())));
+static ENDED: AtomicBool = AtomicBool::new(false);
+
+/// Get the red and the blue channels of a pixel.
+fn red_blue(pixel: u32) -> (u32, u32) {
+    ((pixel >> 16) & 0xFF, pixel & 0xFF)
+}
+
+#[test]
+fn style_animated_to_the_end_style() {
+    let mut sim = Simulator::new();
+    let start = Style::build(|style| style.copy_from(Style::plain()).body_color(Color::RED));
+    let end = Style::build(|style| style.copy_from(Style::plain()).body_color(Color::BLUE));
+    let mut obj = Obj::new(sim.screen()).unwrap();
+    obj.set_pos(10, 10);
+    obj.set_size(40, 40);
+    obj.set_style(&ANIMATED_STYLE);
+
+    StyleAnim::new(&ANIMATED_STYLE, &start, &end)
+        .time(200)
+        .on_end(|| ENDED.store(true, Ordering::SeqCst))
+        .start();
+    sim.advance(100);
+    sim.refresh();
+    let (red, blue) = red_blue(sim.pixel(30, 30));
+    assert!(red > 0 && blue > 0);
+    assert!(!ENDED.load(Ordering::SeqCst));
+
+    sim.advance(200);
+    sim.refresh();
+    let (red, blue) = red_blue(sim.pixel(30, 30));
+    assert!(red < 0x80 && blue > 0x80);
+    assert!(ENDED.load(Ordering::SeqCst));
+}
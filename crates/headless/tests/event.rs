@@ -0,0 +1,86 @@
+use headless::*;
+use lvgl::*;
+use lvgl_sys::*;
+use std::cell::RefCell;
+use std::ptr;
+use std::rc::Rc;
+
+/// Send the press signal to an object, like an input device pressing it.
+fn press(obj: &impl ObjT) {
+    unsafe {
+        let obj = obj.as_lv_obj();
+        let signal = lv_obj_get_signal_func(obj).unwrap();
+        signal(obj, LV_SIGNAL_PRESSED as lv_signal_t, ptr::null_mut());
+    }
+}
+
+#[test]
+fn handler_receives_the_events() {
+    let sim = Simulator::new();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut obj = Obj::new(sim.screen()).unwrap();
+    let log = events.clone();
+    obj.on_event(move |_, event| log.borrow_mut().push(event));
+
+    press(&obj);
+    press(&obj);
+    assert_eq!(*events.borrow(), [Event::Pressed, Event::Pressed]);
+}
+
+#[test]
+fn handler_dropped_with_its_object() {
+    let sim = Simulator::new();
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut obj = Obj::new(sim.screen()).unwrap();
+    let log = events.clone();
+    obj.on_event(move |_, event| log.borrow_mut().push(event));
+    assert_eq!(Rc::strong_count(&events), 2);
+
+    drop(obj);
+    assert_eq!(Rc::strong_count(&events), 1);
+}
+
+#[test]
+fn replaced_and_cleared_handlers_dropped() {
+    let sim = Simulator::new();
+    let first = Rc::new(RefCell::new(Vec::new()));
+    let second = Rc::new(RefCell::new(Vec::new()));
+    let mut obj = Obj::new(sim.screen()).unwrap();
+
+    let log = first.clone();
+    obj.on_event(move |_, event| log.borrow_mut().push(event));
+    let log = second.clone();
+    obj.on_event(move |_, event| log.borrow_mut().push(event));
+    assert_eq!(Rc::strong_count(&first), 1);
+    press(&obj);
+    assert!(first.borrow().is_empty());
+    assert_eq!(*second.borrow(), [Event::Pressed]);
+
+    obj.clear_event_handler();
+    assert_eq!(Rc::strong_count(&second), 1);
+    press(&obj);
+    assert_eq!(second.borrow().len(), 1);
+}
+
+#[test]
+fn handler_deletes_its_object() {
+    let sim = Simulator::new();
+    let slot = Rc::new(RefCell::new(None));
+    let mut obj = Obj::new(sim.screen()).unwrap();
+    let raw = unsafe { obj.as_lv_obj() };
+
+    let own = slot.clone();
+    obj.on_event(move |_, _| {
+        // The handler is released once it returns.
+        own.borrow_mut().take();
+    });
+    *slot.borrow_mut() = Some(obj);
+
+    unsafe {
+        let signal = lv_obj_get_signal_func(raw).unwrap();
+        let res = signal(raw, LV_SIGNAL_PRESSED as lv_signal_t, ptr::null_mut());
+        assert_eq!(res, LV_RES_INV as lv_res_t);
+    }
+    assert!(slot.borrow().is_none());
+    assert_eq!(Rc::strong_count(&slot), 1);
+}
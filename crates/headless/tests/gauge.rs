@@ -0,0 +1,40 @@
+use headless::*;
+use lvgl::*;
+
+use std::fmt::Write;
+
+static NEEDLE_COLORS: [Color; 2] = [Color::RED, Color::BLUE];
+
+#[test]
+fn needles_take_the_static_colors() {
+    let sim = Simulator::new();
+    let mut gauge = Gauge::create(sim.screen(), None).unwrap();
+
+    gauge.set_needle_count(&NEEDLE_COLORS);
+    assert_eq!(gauge.get_needle_count(), 2);
+    let color = gauge.get_needle_color(1).map(Color::to_u32);
+    assert_eq!(color, Some(Color::BLUE.to_u32()));
+    assert!(gauge.get_needle_color(2).is_none());
+}
+
+#[test]
+fn label_formatter_draws_the_labels() {
+    let mut sim = Simulator::new();
+    let mut gauge = Gauge::create(sim.screen(), None).unwrap();
+    gauge.set_size(200, 200);
+    gauge.set_scale(240, 31, 4);
+    gauge.set_range(0, 30);
+    sim.refresh();
+    let plain = sim.framebuffer().to_vec();
+
+    gauge.set_label_formatter(|label, value| {
+        let _ = write!(label, "{}s", value);
+    });
+    sim.refresh();
+    assert_ne!(plain, sim.framebuffer().to_vec());
+    assert_eq!(gauge.get_label_count(), 4);
+
+    gauge.clear_label_formatter();
+    sim.refresh();
+    assert_eq!(plain, sim.framebuffer().to_vec());
+}
@@ -0,0 +1,27 @@
+use headless::*;
+
+#[test]
+fn stopwatch_stopped() {
+    let mut sim = Simulator::new();
+    sim.advance(100);
+    assert_golden(&mut sim, "stopwatch_stopped");
+}
+
+#[test]
+fn stopwatch_paused() {
+    let mut sim = Simulator::new();
+    sim.click_button();
+    sim.advance(12_340);
+    sim.click_button();
+    assert_golden(&mut sim, "stopwatch_paused_12s");
+}
+
+#[test]
+fn stopwatch_reset() {
+    let mut sim = Simulator::new();
+    sim.click_button();
+    sim.advance(5_000);
+    sim.click_button();
+    sim.press_button(1_000);
+    assert_golden(&mut sim, "stopwatch_stopped");
+}
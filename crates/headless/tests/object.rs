@@ -0,0 +1,55 @@
+use headless::*;
+use lvgl::*;
+
+#[test]
+fn owned_object_deleted_on_drop() {
+    let sim = Simulator::new();
+    let count = sim.screen().count_children();
+
+    let obj = Obj::new(sim.screen()).unwrap();
+    assert_eq!(sim.screen().count_children(), count + 1);
+    drop(obj);
+    assert_eq!(sim.screen().count_children(), count);
+}
+
+#[test]
+fn child_deleted_with_its_parent() {
+    let sim = Simulator::new();
+    let count = sim.screen().count_children();
+    let parent = Obj::new(sim.screen()).unwrap();
+
+    // The child handle leaves the deletion to the parent.
+    let child = Obj::create(&parent, None).unwrap();
+    Label::create(&child, None).unwrap();
+    drop(child);
+    assert_eq!(parent.count_children(), 1);
+
+    drop(parent);
+    assert_eq!(sim.screen().count_children(), count);
+}
+
+#[test]
+fn child_taken_into_ownership_deleted_on_drop() {
+    let sim = Simulator::new();
+    let parent = Obj::new(sim.screen()).unwrap();
+
+    let child = Obj::create(&parent, None).unwrap().into_owned();
+    assert_eq!(parent.count_children(), 1);
+    drop(child);
+    assert_eq!(parent.count_children(), 0);
+}
+
+#[test]
+fn children_iterated_from_the_youngest() {
+    let sim = Simulator::new();
+    let parent = Obj::new(sim.screen()).unwrap();
+    let mut first = Obj::create(&parent, None).unwrap();
+    first.set_x(1);
+    let mut second = Obj::create(&parent, None).unwrap();
+    second.set_x(2);
+
+    let xs: Vec<_> = parent.children().map(|child| child.get_x()).collect();
+    assert_eq!(xs, [2, 1]);
+    let parent_of_first = first.get_parent().map(|obj| unsafe { obj.as_lv_obj() });
+    assert_eq!(parent_of_first, Some(unsafe { parent.as_lv_obj() }));
+}
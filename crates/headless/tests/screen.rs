@@ -0,0 +1,48 @@
+use headless::*;
+use lvgl::*;
+
+#[test]
+fn loaded_screen_is_the_active_one() {
+    let sim = Simulator::new();
+    let screen = Screen::new().unwrap();
+    assert!(sim.screen().is_active());
+    assert!(!screen.is_active());
+
+    screen.load();
+    assert!(screen.is_active());
+    assert!(!sim.screen().is_active());
+
+    sim.screen().load();
+    assert!(screen.delete().is_ok());
+}
+
+#[test]
+fn active_screen_is_not_deleted() {
+    let sim = Simulator::new();
+    let screen = Screen::new().unwrap();
+    Label::create(&screen, None).unwrap();
+    screen.load();
+
+    let screen = screen.delete().unwrap_err();
+    assert!(screen.is_active());
+    assert_eq!(screen.count_children(), 1);
+
+    sim.screen().load();
+    assert!(screen.delete().is_ok());
+}
+
+#[test]
+fn switching_screens_redraws_the_display() {
+    let mut sim = Simulator::new();
+    sim.refresh();
+    let hmi = sim.framebuffer().to_vec();
+
+    let screen = Screen::new().unwrap();
+    screen.load();
+    sim.refresh();
+    assert_ne!(hmi, sim.framebuffer().to_vec());
+
+    sim.screen().load();
+    sim.refresh();
+    assert_eq!(hmi, sim.framebuffer().to_vec());
+}
@@ -0,0 +1,73 @@
+use api::*;
+use headless::*;
+use lvgl::*;
+
+#[test]
+fn renders_stopwatch_screen() {
+    let mut sim = Simulator::new();
+    sim.advance(100);
+    sim.refresh();
+
+    // The screen background is a gradient, so the top and bottom rows differ.
+    assert_ne!(sim.pixel(0, 0), sim.pixel(0, HEIGHT - 1));
+    assert!(!sim.led(LEDs_GREEN));
+    assert!(!sim.led(LEDs_RED));
+}
+
+#[test]
+fn click_starts_and_pauses_timer() {
+    let mut sim = Simulator::new();
+
+    sim.click_button();
+    assert!(sim.led(LEDs_GREEN));
+    assert!(!sim.led(LEDs_RED));
+
+    sim.refresh();
+    let started = sim.framebuffer().to_vec();
+    sim.advance(1_500);
+    sim.refresh();
+    assert_ne!(started, sim.framebuffer().to_vec());
+
+    sim.click_button();
+    assert!(!sim.led(LEDs_GREEN));
+    assert!(sim.led(LEDs_RED));
+
+    sim.refresh();
+    let paused = sim.framebuffer().to_vec();
+    sim.advance(1_500);
+    sim.refresh();
+    assert_eq!(paused, sim.framebuffer().to_vec());
+}
+
+#[test]
+fn long_press_resets_timer() {
+    let mut sim = Simulator::new();
+    sim.refresh();
+    let reset = sim.framebuffer().to_vec();
+
+    sim.click_button();
+    sim.advance(1_500);
+    sim.click_button();
+    sim.press_button(1_000);
+    assert!(!sim.led(LEDs_GREEN));
+    assert!(!sim.led(LEDs_RED));
+
+    sim.refresh();
+    assert_eq!(reset, sim.framebuffer().to_vec());
+}
+
+#[test]
+fn user_button_is_an_encoder() {
+    let mut sim = Simulator::new();
+    let mut group = Group::new().unwrap();
+    let mut button = Button::create(sim.screen(), None).unwrap();
+    button.set_toggle(true);
+    group.add(&button);
+    sim.user_button().set_group(&group);
+
+    // Releasing the encoder sends the enter key to the focused object.
+    sim.click_button();
+    assert_eq!(button.get_state(), ButtonState::ToggledReleased);
+    sim.click_button();
+    assert_eq!(button.get_state(), ButtonState::Released);
+}
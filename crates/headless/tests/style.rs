@@ -0,0 +1,35 @@
+use headless::*;
+use lvgl::*;
+
+static REPORTED_STYLE: Lazy<Style> =
+    Lazy::new(|| Style::build(|style| style.copy_from(Style::plain()).body_color(Color::RED)));
+
+/// Get the red and the blue channels of a pixel.
+fn red_blue(pixel: u32) -> (u32, u32) {
+    ((pixel >> 16) & 0xFF, pixel & 0xFF)
+}
+
+#[test]
+fn applied_changes_are_reported_to_the_objects() {
+    let mut sim = Simulator::new();
+    let mut obj = Obj::new(sim.screen()).unwrap();
+    obj.set_pos(10, 10);
+    obj.set_size(40, 40);
+    obj.set_style(&REPORTED_STYLE);
+    sim.refresh();
+    let (red, blue) = red_blue(sim.pixel(30, 30));
+    assert!(red > 0x80 && blue < 0x80);
+
+    // The changes are only written by `apply`.
+    let unapplied = unsafe { REPORTED_STYLE.modify_live() }.body_color(Color::BLUE);
+    drop(unapplied);
+    sim.refresh();
+    assert_eq!(red_blue(sim.pixel(30, 30)), (red, blue));
+
+    unsafe { REPORTED_STYLE.modify_live() }
+        .body_color(Color::BLUE)
+        .apply();
+    sim.refresh();
+    let (red, blue) = red_blue(sim.pixel(30, 30));
+    assert!(red < 0x80 && blue > 0x80);
+}
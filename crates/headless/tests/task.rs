@@ -0,0 +1,73 @@
+use headless::*;
+use lvgl::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+#[test]
+fn task_runs_until_dropped() {
+    let mut sim = Simulator::new();
+    let runs = Rc::new(Cell::new(0));
+    let counter = runs.clone();
+    let task = Task::new(100, TaskPrio::Mid, move || counter.set(counter.get() + 1)).unwrap();
+
+    sim.advance(1_000);
+    assert!(runs.get() >= 9 && runs.get() <= 10);
+
+    drop(task);
+    assert_eq!(Rc::strong_count(&runs), 1);
+    let count = runs.get();
+    sim.advance(1_000);
+    assert_eq!(runs.get(), count);
+}
+
+#[test]
+fn paused_task_does_not_run() {
+    let mut sim = Simulator::new();
+    let runs = Rc::new(Cell::new(0));
+    let counter = runs.clone();
+    let mut task = Task::new(100, TaskPrio::Mid, move || counter.set(counter.get() + 1)).unwrap();
+
+    task.pause();
+    sim.advance(1_000);
+    assert_eq!(runs.get(), 0);
+
+    task.resume();
+    sim.advance(1_000);
+    assert!(runs.get() > 0);
+}
+
+#[test]
+fn task_dropped_by_its_closure() {
+    let mut sim = Simulator::new();
+    let runs = Rc::new(Cell::new(0));
+    let slot: Rc<RefCell<Option<Task>>> = Rc::new(RefCell::new(None));
+    let counter = runs.clone();
+    let own = slot.clone();
+    let task = Task::new(100, TaskPrio::Mid, move || {
+        counter.set(counter.get() + 1);
+        // The closure is released once it returns.
+        own.borrow_mut().take();
+    })
+    .unwrap();
+    *slot.borrow_mut() = Some(task);
+
+    sim.advance(1_000);
+    assert_eq!(runs.get(), 1);
+    assert!(slot.borrow().is_none());
+    assert_eq!(Rc::strong_count(&runs), 1);
+    assert_eq!(Rc::strong_count(&slot), 1);
+}
+
+#[test]
+fn one_shot_task_runs_once() {
+    let mut sim = Simulator::new();
+    let runs = Rc::new(Cell::new(0));
+    let counter = runs.clone();
+    assert!(Task::once(200, TaskPrio::Mid, move || counter.set(counter.get() + 1)));
+
+    sim.advance(100);
+    assert_eq!(runs.get(), 0);
+    sim.advance(1_000);
+    assert_eq!(runs.get(), 1);
+    assert_eq!(Rc::strong_count(&runs), 1);
+}
@@ -0,0 +1,58 @@
+use lvgl::*;
+
+type ColorsCallback =
+    unsafe extern "C" fn(x1: i32, y1: i32, x2: i32, y2: i32, colors: *const Color);
+type FillCallback = unsafe extern "C" fn(x1: i32, y1: i32, x2: i32, y2: i32, color: Color);
+
+/// The display callbacks of the system, see `DisplayCallbacks` in display.h.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DisplayCallbacks {
+    flush: Option<ColorsCallback>,
+    fill: Option<FillCallback>,
+    map: Option<ColorsCallback>,
+}
+
+impl DisplayDriver for DisplayCallbacks {
+    fn flush(&mut self, area: Area, colors: &[Color]) {
+        match self.flush {
+            Some(flush) => unsafe { flush(area.x1, area.y1, area.x2, area.y2, colors.as_ptr()) },
+            None => Display::flush_ready(),
+        }
+    }
+
+    fn fill(&mut self, area: Area, color: Color) {
+        if let Some(fill) = self.fill {
+            unsafe { fill(area.x1, area.y1, area.x2, area.y2, color) }
+        }
+    }
+
+    fn map(&mut self, area: Area, colors: &[Color]) {
+        if let Some(map) = self.map {
+            unsafe { map(area.x1, area.y1, area.x2, area.y2, colors.as_ptr()) }
+        }
+    }
+}
+
+/// Register the display of the system as the lvgl display driver, so the
+/// display is drawn through the lvgl crate. The callbacks are copied. The
+/// driver cannot be replaced while lvgl may draw through it, so only the
+/// first call registers the display and the following ones return `false`.
+///
+/// # Safety
+///
+/// `callbacks` must point to valid callbacks, which must follow the contract
+/// of the lvgl display driver.
+#[no_mangle]
+pub unsafe extern "C" fn display_register(callbacks: *const DisplayCallbacks) -> bool {
+    static mut DISPLAY: Option<DisplayCallbacks> = None;
+
+    if DISPLAY.is_some() || callbacks.is_null() {
+        return false;
+    }
+    DISPLAY = Some(*callbacks);
+    match DISPLAY {
+        Some(ref mut display) => Display::register(display),
+        None => false,
+    }
+}
@@ -1,3 +1,6 @@
+use crate::theme::{init_brand_theme, LARGE_TEXT_STYLE, SCREEN_STYLE, TEXT_STYLE};
+use crate::user_button::register_user_button;
+
 use api::*;
 use lvgl::*;
 
@@ -10,9 +13,11 @@ enum TimerState {
 }
 
 pub struct HmiManager {
-    title: Option<Label>,
-    gauge: Option<Gauge>,
-    timestamp: Option<Label>,
+    screen: Option<&'static Screen>,
+    title: Option<Child<'static, Label>>,
+    gauge: Option<Child<'static, Gauge>>,
+    timestamp: Option<Child<'static, Label>>,
+    user_button: Option<Indev>,
     timer_state: TimerState,
     timer_elapsed: u32,
     last_ticks: u32,
@@ -23,9 +28,11 @@ pub struct HmiManager {
 impl HmiManager {
     pub const fn new_empty() -> Self {
         HmiManager {
+            screen: None,
             title: None,
             gauge: None,
             timestamp: None,
+            user_button: None,
             timer_state: TimerState::Stopped,
             timer_elapsed: 0,
             last_ticks: 0,
@@ -35,31 +42,27 @@ impl HmiManager {
     }
 
     pub fn init(&mut self) {
-        let screen_style = Style::screen();
-        (*screen_style).body.main_color = Color::BLUE.into();
-        (*screen_style).body.grad_color = Color::NAVY.into();
-
-        static mut TITLE_STYLE: Style = Style::new_empty();
-        let title_style = unsafe { &mut TITLE_STYLE };
-        title_style.copy_from(Style::pretty());
-        (*title_style).text.font = Font::dejavu_20().into();
-        (*title_style).text.color = Color::WHITE.into();
-
-        if let Some(mut title) = Label::new() {
-            title.set_style(title_style);
+        init_brand_theme();
+        self.user_button = register_user_button();
+
+        // The screen lives as long as the program, like the manager.
+        let screen = match Screen::new() {
+            Some(screen) => screen.leak(),
+            None => return,
+        };
+        screen.set_style(&SCREEN_STYLE);
+        screen.load();
+        let screen: &'static Screen = screen;
+        self.screen = Some(screen);
+
+        if let Some(mut title) = Label::create(screen, None) {
+            title.set_style(&TEXT_STYLE);
             title.set_static_text(b"Stopwatch\0");
             title.align_to_parent(Align::InTopMid, 0, 10);
             self.title = Some(title);
         }
 
-        static mut GAUGE_STYLE: Style = Style::new_empty();
-        let gauge_style = unsafe { &mut GAUGE_STYLE };
-        gauge_style.copy_from(Style::pretty());
-        (*gauge_style).text.font = Font::dejavu_20().into();
-        (*gauge_style).text.color = Color::WHITE.into();
-
-        if let Some(mut gauge) = Gauge::new() {
-            gauge.set_style(gauge_style);
+        if let Some(mut gauge) = Gauge::create(screen, None) {
             gauge.set_size(220, 220);
             gauge.set_scale(270, 61, 7);
             gauge.set_range(0, 60);
@@ -67,21 +70,22 @@ impl HmiManager {
             self.gauge = Some(gauge);
         }
 
-        static mut TIMESTAMP_STYLE: Style = Style::new_empty();
-        let timestamp_style = unsafe { &mut TIMESTAMP_STYLE };
-        timestamp_style.copy_from(Style::pretty());
-        (*timestamp_style).text.font = Font::dejavu_40().into();
-        (*timestamp_style).text.color = Color::WHITE.into();
-
-        if let Some(mut timestamp) = Label::new() {
-            timestamp.set_style(timestamp_style);
+        if let Some(mut timestamp) = Label::create(screen, None) {
+            timestamp.set_style(&LARGE_TEXT_STYLE);
             timestamp.set_static_text(b"00:00.00\0");
             timestamp.align_to_parent(Align::InBottomMid, 0, 0);
             self.timestamp = Some(timestamp);
         }
+    }
+
+    /// Get the screen of the HMI.
+    pub fn screen(&self) -> Option<&'static Screen> {
+        self.screen
+    }
 
-        // Update display after changing styles.
-        Style::report_all_mod();
+    /// Get the input device of the user button, which acts as an encoder.
+    pub fn user_button(&self) -> Option<Indev> {
+        self.user_button
     }
 
     pub unsafe fn update(&mut self) {
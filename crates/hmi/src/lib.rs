@@ -1,7 +1,13 @@
 #![no_std]
 
+mod display;
 mod hmi_manager;
 mod interface;
+mod theme;
+mod user_button;
 
+pub use self::display::*;
 pub use self::hmi_manager::*;
 pub use self::interface::*;
+pub use self::theme::*;
+pub use self::user_button::*;
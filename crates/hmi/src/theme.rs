@@ -0,0 +1,51 @@
+use lvgl::*;
+
+/// Background of the screens: a blue gradient.
+pub static SCREEN_STYLE: Lazy<Style> = Lazy::new(|| {
+    Style::build(|style| {
+        style
+            .copy_from(Style::screen())
+            .body_main_color(Color::BLUE)
+            .body_grad_color(Color::NAVY)
+    })
+});
+/// Regular text, in white.
+pub static TEXT_STYLE: Lazy<Style> = Lazy::new(|| {
+    Style::build(|style| {
+        style
+            .copy_from(Style::pretty())
+            .text_font(Font::dejavu_20())
+            .text_color(Color::WHITE)
+    })
+});
+/// Large text, for the values to read from afar.
+pub static LARGE_TEXT_STYLE: Lazy<Style> = Lazy::new(|| {
+    Style::build(|style| {
+        style
+            .copy_from(Style::pretty())
+            .text_font(Font::dejavu_40())
+            .text_color(Color::WHITE)
+    })
+});
+
+/// The theme shared by the product and its derivatives, built on top of the
+/// built-in styles.
+static BRAND_THEME: Lazy<Theme> = Lazy::new(|| {
+    let builtins = Theme::new_from_builtins();
+    Theme {
+        bg: Some(&SCREEN_STYLE),
+        label: ThemeLabel {
+            prim: Some(&TEXT_STYLE),
+            sec: Some(&LARGE_TEXT_STYLE),
+            ..builtins.label
+        },
+        gauge: Some(&TEXT_STYLE),
+        ..builtins
+    }
+});
+
+/// Make the brand theme the current theme. The objects created afterwards use
+/// it.
+pub fn init_brand_theme() {
+    Theme::set_current(&BRAND_THEME);
+}
@@ -0,0 +1,34 @@
+use api::*;
+use lvgl::*;
+
+/// The user button as an lvgl encoder which never rotates: pressing it
+/// presses the focused object of the group assigned to the input device.
+pub struct UserButton;
+
+impl InputDevice for UserButton {
+    fn device_type(&self) -> InputDeviceType {
+        InputDeviceType::Encoder
+    }
+
+    fn read(&mut self) -> InputData {
+        InputData::Encoder {
+            diff: 0,
+            pressed: unsafe { drivers_is_button_pressed() },
+        }
+    }
+}
+
+/// Register the user button as an lvgl input device. lvgl cannot remove an
+/// input device, so only the first call registers it and the following ones
+/// return the same device.
+pub fn register_user_button() -> Option<Indev> {
+    static mut USER_BUTTON: UserButton = UserButton;
+    static mut INDEV: Option<Indev> = None;
+
+    unsafe {
+        if INDEV.is_none() {
+            INDEV = Indev::register(&mut USER_BUTTON);
+        }
+        INDEV
+    }
+}
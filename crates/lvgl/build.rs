@@ -2,10 +2,43 @@ use std::env;
 
 fn main() {
     set_config_value("DEP_LVGL_LV_COLOR_DEPTH", "lv_color_depth");
+    set_config_bool("DEP_LVGL_LV_THEME_LIVE_UPDATE", "lv_theme_live_update");
+    set_config_bool("DEP_LVGL_USE_LV_ANIMATION", "use_lv_animation");
+    set_config_bool("DEP_LVGL_USE_LV_ARC", "use_lv_arc");
+    set_config_bool("DEP_LVGL_USE_LV_BAR", "use_lv_bar");
+    set_config_bool("DEP_LVGL_USE_LV_BTN", "use_lv_btn");
+    set_config_bool("DEP_LVGL_USE_LV_BTNM", "use_lv_btnm");
+    set_config_bool("DEP_LVGL_USE_LV_CALENDAR", "use_lv_calendar");
+    set_config_bool("DEP_LVGL_USE_LV_CB", "use_lv_cb");
+    set_config_bool("DEP_LVGL_USE_LV_CHART", "use_lv_chart");
+    set_config_bool("DEP_LVGL_USE_LV_CONT", "use_lv_cont");
+    set_config_bool("DEP_LVGL_USE_LV_DDLIST", "use_lv_ddlist");
     set_config_bool("DEP_LVGL_USE_LV_GAUGE", "use_lv_gauge");
+    set_config_bool("DEP_LVGL_USE_LV_GROUP", "use_lv_group");
     set_config_bool("DEP_LVGL_USE_LV_IMG", "use_lv_img");
+    set_config_bool("DEP_LVGL_USE_LV_IMGBTN", "use_lv_imgbtn");
+    set_config_bool("DEP_LVGL_USE_LV_KB", "use_lv_kb");
     set_config_bool("DEP_LVGL_USE_LV_LABEL", "use_lv_label");
+    set_config_bool("DEP_LVGL_USE_LV_LED", "use_lv_led");
+    set_config_bool("DEP_LVGL_USE_LV_LINE", "use_lv_line");
+    set_config_bool("DEP_LVGL_USE_LV_LIST", "use_lv_list");
     set_config_bool("DEP_LVGL_USE_LV_LMETER", "use_lv_lmeter");
+    set_config_bool("DEP_LVGL_USE_LV_MBOX", "use_lv_mbox");
+    set_config_bool("DEP_LVGL_USE_LV_PAGE", "use_lv_page");
+    set_config_bool("DEP_LVGL_USE_LV_PRELOAD", "use_lv_preload");
+    set_config_bool("DEP_LVGL_USE_LV_ROLLER", "use_lv_roller");
+    set_config_bool("DEP_LVGL_USE_LV_SLIDER", "use_lv_slider");
+    set_config_bool("DEP_LVGL_USE_LV_SW", "use_lv_sw");
+    set_config_bool("DEP_LVGL_USE_LV_TA", "use_lv_ta");
+    set_config_bool("DEP_LVGL_USE_LV_TABVIEW", "use_lv_tabview");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_ALIEN", "use_lv_theme_alien");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_DEFAULT", "use_lv_theme_default");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_MATERIAL", "use_lv_theme_material");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_MONO", "use_lv_theme_mono");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_NEMO", "use_lv_theme_nemo");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_NIGHT", "use_lv_theme_night");
+    set_config_bool("DEP_LVGL_USE_LV_THEME_ZEN", "use_lv_theme_zen");
+    set_config_bool("DEP_LVGL_USE_LV_WIN", "use_lv_win");
 }
 
 fn set_config_bool(varname: &str, name: &str) {
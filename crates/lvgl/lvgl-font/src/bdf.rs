@@ -0,0 +1,223 @@
+use crate::error::Error;
+use crate::{Glyph, Rasterized};
+
+/// A glyph as described in a BDF font.
+struct BdfGlyph {
+    encoding: i64,
+    width: i32,
+    bbx: (i32, i32, i32, i32),
+    rows: Vec<Vec<u8>>,
+}
+
+/// Rasterize the glyphs of a BDF font for the given sorted letters. The glyphs
+/// are as high as the ascent and the descent of the font, and as wide as their
+/// advance.
+pub(crate) fn rasterize(text: &str, letters: &[u32]) -> Result<Rasterized, Error> {
+    let mut ascent = None;
+    let mut descent = None;
+    let mut bounding_box = None;
+    let mut glyphs = Vec::new();
+
+    let mut lines = text.lines().enumerate();
+    while let Some((n, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("FONT_ASCENT") => ascent = Some(parse(n, words.next())?),
+            Some("FONT_DESCENT") => descent = Some(parse(n, words.next())?),
+            Some("FONTBOUNDINGBOX") => {
+                let _width: i32 = parse(n, words.next())?;
+                let height: i32 = parse(n, words.next())?;
+                let _x: i32 = parse(n, words.next())?;
+                let y: i32 = parse(n, words.next())?;
+                bounding_box = Some((height, y));
+            }
+            Some("STARTCHAR") => glyphs.push(parse_glyph(&mut lines)?),
+            _ => {}
+        }
+    }
+
+    // Prefer the properties, as the bounding box may be larger than the
+    // line.
+    let (ascent, descent) = match (ascent, descent, bounding_box) {
+        (Some(ascent), Some(descent), _) => (ascent, descent),
+        (_, _, Some((height, y))) => (height + y, -y),
+        _ => return Err(Error::Font("Missing FONTBOUNDINGBOX".to_owned())),
+    };
+    let height = ascent + descent;
+    if height <= 0 || height > i32::from(u8::max_value()) {
+        return Err(Error::Options(format!("Unsupported height {}", height)));
+    }
+
+    let mut glyphs: Vec<Glyph> = glyphs
+        .iter()
+        .filter(|glyph| glyph.encoding >= 0 && glyph.encoding <= i64::from(u32::max_value()))
+        .filter(|glyph| letters.binary_search(&(glyph.encoding as u32)).is_ok())
+        .map(|glyph| draw(glyph, ascent, height))
+        .collect::<Result<_, _>>()?;
+    glyphs.sort_by_key(|glyph| glyph.letter);
+    glyphs.dedup_by_key(|glyph| glyph.letter);
+
+    Ok(Rasterized {
+        height: height as u8,
+        glyphs,
+    })
+}
+
+/// Parse a glyph, from the line after STARTCHAR to ENDCHAR.
+fn parse_glyph<'a>(lines: &mut impl Iterator<Item = (usize, &'a str)>) -> Result<BdfGlyph, Error> {
+    let mut glyph = BdfGlyph {
+        encoding: -1,
+        width: 0,
+        bbx: (0, 0, 0, 0),
+        rows: Vec::new(),
+    };
+    while let Some((n, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("ENCODING") => glyph.encoding = parse(n, words.next())?,
+            Some("DWIDTH") => glyph.width = parse(n, words.next())?,
+            Some("BBX") => {
+                glyph.bbx = (
+                    parse(n, words.next())?,
+                    parse(n, words.next())?,
+                    parse(n, words.next())?,
+                    parse(n, words.next())?,
+                );
+            }
+            Some("BITMAP") => {
+                for _ in 0..glyph.bbx.1 {
+                    let (n, line) = lines.next().ok_or_else(unexpected_end)?;
+                    glyph.rows.push(parse_row(n, line.trim())?);
+                }
+            }
+            Some("ENDCHAR") => return Ok(glyph),
+            _ => {}
+        }
+    }
+    Err(unexpected_end())
+}
+
+/// Draw a glyph, placing its bounding box relatively to the baseline.
+fn draw(glyph: &BdfGlyph, ascent: i32, height: i32) -> Result<Glyph, Error> {
+    if glyph.width < 0 || glyph.width > i32::from(u8::max_value()) {
+        return Err(Error::Options(format!(
+            "Unsupported width {} of letter {}",
+            glyph.width, glyph.encoding
+        )));
+    }
+    let width = glyph.width;
+    let (bbx_width, bbx_height, x_ofs, y_ofs) = glyph.bbx;
+    let top = ascent - (y_ofs + bbx_height);
+
+    let mut pixels = vec![0; (width * height) as usize];
+    for (row, bytes) in glyph.rows.iter().enumerate() {
+        let y = top + row as i32;
+        if y < 0 || y >= height {
+            continue;
+        }
+        for col in 0..bbx_width {
+            let x = x_ofs + col;
+            let byte = bytes.get((col / 8) as usize).cloned().unwrap_or(0);
+            if x >= 0 && x < width && byte & (0x80 >> (col % 8)) != 0 {
+                pixels[(y * width + x) as usize] = 0xff;
+            }
+        }
+    }
+
+    Ok(Glyph {
+        letter: glyph.encoding as u32,
+        width: width as u8,
+        pixels,
+    })
+}
+
+fn parse_row(n: usize, row: &str) -> Result<Vec<u8>, Error> {
+    if row.len() % 2 != 0 || !row.is_ascii() {
+        return Err(invalid(n));
+    }
+    (0..row.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&row[i..i + 2], 16).map_err(|_| invalid(n)))
+        .collect()
+}
+
+fn parse<T: std::str::FromStr>(n: usize, word: Option<&str>) -> Result<T, Error> {
+    word.and_then(|word| word.parse().ok())
+        .ok_or_else(|| invalid(n))
+}
+
+fn invalid(n: usize) -> Error {
+    Error::Font(format!("Invalid line {}", n + 1))
+}
+
+fn unexpected_end() -> Error {
+    Error::Font("Unexpected end of file".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FONT: &str = "\
+STARTFONT 2.1
+FONTBOUNDINGBOX 4 4 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 3
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 3
+STARTCHAR wrapped
+ENCODING 4294967361
+DWIDTH 2 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+STARTCHAR A
+ENCODING 65
+DWIDTH 4 0
+BBX 3 2 0 0
+BITMAP
+E0
+A0
+ENDCHAR
+STARTCHAR B
+ENCODING 66
+DWIDTH 4 0
+BBX 3 2 0 0
+BITMAP
+E0
+E0
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn rasterize_places_the_bitmap_on_the_baseline() {
+        let font = rasterize(FONT, &[65]).unwrap();
+        assert_eq!(font.height, 4);
+        assert_eq!(font.glyphs.len(), 1);
+        let glyph = &font.glyphs[0];
+        assert_eq!(glyph.letter, 65);
+        assert_eq!(glyph.width, 4);
+        #[rustfmt::skip]
+        assert_eq!(glyph.pixels, [
+            0x00, 0x00, 0x00, 0x00,
+            0xff, 0xff, 0xff, 0x00,
+            0xff, 0x00, 0xff, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ]);
+    }
+
+    #[test]
+    fn rasterize_skips_the_letters_not_asked() {
+        let font = rasterize(FONT, &[66, 67]).unwrap();
+        let letters: Vec<u32> = font.glyphs.iter().map(|glyph| glyph.letter).collect();
+        assert_eq!(letters, [66]);
+    }
+
+    #[test]
+    fn rasterize_rejects_a_font_without_height() {
+        assert!(rasterize("STARTFONT 2.1\nENDFONT\n", &[65]).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::error::Error;
+use crate::{bdf, generate, Rasterized};
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+enum Source {
+    #[cfg(feature = "ttf")]
+    Ttf {
+        path: PathBuf,
+        size: u8,
+    },
+    Bdf {
+        path: PathBuf,
+    },
+}
+
+/// Converter of a font into the Rust source of an lvgl font.
+pub struct FontConverter {
+    name: String,
+    source: Source,
+    bpp: u8,
+    monospace: u8,
+    ranges: Vec<(char, char)>,
+}
+
+impl FontConverter {
+    /// Convert the TrueType font at `path`, rasterized at `size` pixels. The
+    /// generated function is called `name`.
+    #[cfg(feature = "ttf")]
+    pub fn ttf(name: &str, path: impl AsRef<Path>, size: u8) -> Self {
+        FontConverter::new(
+            name,
+            Source::Ttf {
+                path: path.as_ref().to_owned(),
+                size,
+            },
+        )
+    }
+
+    /// Convert the BDF bitmap font at `path`, at its own size. The generated
+    /// function is called `name`.
+    pub fn bdf(name: &str, path: impl AsRef<Path>) -> Self {
+        FontConverter::new(
+            name,
+            Source::Bdf {
+                path: path.as_ref().to_owned(),
+            },
+        )
+    }
+
+    fn new(name: &str, source: Source) -> Self {
+        FontConverter {
+            name: name.to_owned(),
+            source,
+            bpp: 4,
+            monospace: 0,
+            ranges: Vec::new(),
+        }
+    }
+
+    /// Set the bits per pixel of the glyphs: 1, 2, 4 (the default) or 8.
+    pub fn bpp(mut self, bpp: u8) -> Self {
+        self.bpp = bpp;
+        self
+    }
+
+    /// Advance every letter by `width` pixels, e.g. to get tabular digits. 0
+    /// (the default) advances each letter by its own width.
+    pub fn monospace(mut self, width: u8) -> Self {
+        self.monospace = width;
+        self
+    }
+
+    /// Add the letters from `first` to `last` to the font. Without any range,
+    /// the printable ASCII letters are converted.
+    pub fn range(mut self, first: char, last: char) -> Self {
+        self.ranges.push((first, last));
+        self
+    }
+
+    /// Generate the font and write it to `path`, asking Cargo to run the build
+    /// script again when the font changes.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let code = self.generate()?;
+        fs::write(path, code)?;
+        println!("cargo:rerun-if-changed={}", self.path().display());
+        Ok(())
+    }
+
+    /// Generate the Rust source of the font.
+    pub fn generate(&self) -> Result<String, Error> {
+        if !is_identifier(&self.name) {
+            return Err(Error::Options(format!(
+                "{:?} is not a lowercase identifier",
+                self.name
+            )));
+        }
+        if ![1, 2, 4, 8].contains(&self.bpp) {
+            return Err(Error::Options(format!(
+                "{} bits per pixel is not supported",
+                self.bpp
+            )));
+        }
+
+        let letters = self.letters()?;
+        let font = self.rasterize(&letters)?;
+        let description = format!("{}", self.path().display());
+        generate::generate(
+            &self.name,
+            &description,
+            &font,
+            &letters,
+            self.bpp,
+            self.monospace,
+        )
+    }
+
+    fn path(&self) -> &Path {
+        match &self.source {
+            #[cfg(feature = "ttf")]
+            Source::Ttf { path, .. } => path,
+            Source::Bdf { path } => path,
+        }
+    }
+
+    /// Get the sorted letters of the ranges.
+    fn letters(&self) -> Result<Vec<u32>, Error> {
+        let mut letters = Vec::new();
+        if self.ranges.is_empty() {
+            letters.extend(u32::from(' ')..=u32::from('~'));
+        }
+        for &(first, last) in &self.ranges {
+            if first > last {
+                return Err(Error::Options(format!(
+                    "Empty range {:?}..={:?}",
+                    first, last
+                )));
+            }
+            letters.extend(u32::from(first)..=u32::from(last));
+        }
+        letters.sort();
+        letters.dedup();
+        // lvgl ends the list of letters of sparse fonts with 0.
+        if letters[0] == 0 {
+            return Err(Error::Options("The nul letter is not supported".to_owned()));
+        }
+        Ok(letters)
+    }
+
+    fn rasterize(&self, letters: &[u32]) -> Result<Rasterized, Error> {
+        match &self.source {
+            #[cfg(feature = "ttf")]
+            Source::Ttf { path, size } => {
+                let data = fs::read(path)?;
+                crate::ttf::rasterize(data, *size, letters)
+            }
+            Source::Bdf { path } => {
+                let text = fs::read_to_string(path)?;
+                bdf::rasterize(&text, letters)
+            }
+        }
+    }
+}
+
+/// Check the name can be used for the function and the statics of the font.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    if name == "_" {
+        return false;
+    }
+    match chars.next() {
+        Some(c) if c == '_' || c.is_ascii_lowercase() => {}
+        _ => return false,
+    }
+    chars.all(|c| c == '_' || c.is_ascii_lowercase() || c.is_ascii_digit())
+}
@@ -0,0 +1,32 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The font could not be read or the generated file written.
+    Io(io::Error),
+    /// The font is invalid.
+    Font(String),
+    /// The options are invalid, or the font does not fit in an lvgl font.
+    Options(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::Font(msg) => write!(f, "Invalid font: {}", msg),
+            Error::Options(msg) => write!(f, "Invalid options: {}", msg),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl From<io::Error> for Error {
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
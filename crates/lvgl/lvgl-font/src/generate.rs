@@ -0,0 +1,314 @@
+use crate::error::Error;
+use crate::{Glyph, Rasterized};
+
+use std::fmt::Write;
+
+/// The largest index of a glyph bitmap, which lvgl keeps in 24 bits.
+const MAX_GLYPH_INDEX: usize = (1 << 24) - 1;
+
+/// Generate the statics of the font and the function returning it.
+///
+/// A font covering consecutive letters is continuous: it has a descriptor for
+/// each letter, with an empty one for the letters missing from the source. The
+/// other fonts are sparse and only list the letters found.
+pub(crate) fn generate(
+    name: &str,
+    description: &str,
+    font: &Rasterized,
+    letters: &[u32],
+    bpp: u8,
+    monospace: u8,
+) -> Result<String, Error> {
+    if font.glyphs.is_empty() {
+        return Err(Error::Options(format!("No glyphs in {}", description)));
+    }
+    let first = letters[0];
+    let last = letters[letters.len() - 1];
+    let continuous = (last - first) as usize + 1 == letters.len();
+
+    let missing = Glyph {
+        letter: 0,
+        width: 0,
+        pixels: Vec::new(),
+    };
+    let glyphs: Vec<&Glyph> = if continuous {
+        let mut found = font.glyphs.iter().peekable();
+        letters
+            .iter()
+            .map(|&letter| match found.peek() {
+                Some(glyph) if glyph.letter == letter => found.next().unwrap(),
+                _ => &missing,
+            })
+            .collect()
+    } else {
+        font.glyphs.iter().collect()
+    };
+    if glyphs.len() > u16::max_value() as usize {
+        return Err(Error::Options(format!(
+            "Too many glyphs in {}",
+            description
+        )));
+    }
+
+    let mut bitmap = Vec::new();
+    let mut glyph_dsc = Vec::new();
+    for glyph in &glyphs {
+        if bitmap.len() > MAX_GLYPH_INDEX {
+            return Err(Error::Options(format!(
+                "Too many pixels in {}",
+                description
+            )));
+        }
+        glyph_dsc.push(u32::from(glyph.width) | (bitmap.len() as u32) << 8);
+        pack(glyph, bpp, &mut bitmap);
+    }
+
+    let upper = name.to_uppercase();
+    let mut code = String::new();
+    writeln!(code, "// Generated by lvgl-font from {}.", description).unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(code, "static {}_BITMAP: [u8; {}] = [", upper, bitmap.len()).unwrap();
+    write_array(&mut code, &bitmap, 16, |value| format!("0x{:02x},", value));
+    writeln!(code, "];").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(
+        code,
+        "static {}_GLYPH_DSC: [u32; {}] = [",
+        upper,
+        glyph_dsc.len()
+    )
+    .unwrap();
+    write_array(&mut code, &glyph_dsc, 8, |value| {
+        format!("0x{:08x},", value)
+    });
+    writeln!(code, "];").unwrap();
+    writeln!(code).unwrap();
+
+    let (unicode_list, get_bitmap, get_width) = if continuous {
+        ("::core::ptr::null()".to_owned(), "CONTINUOUS", "CONTINUOUS")
+    } else {
+        let mut list: Vec<u32> = glyphs.iter().map(|glyph| glyph.letter).collect();
+        list.push(0);
+        writeln!(
+            code,
+            "static {}_UNICODE_LIST: [u32; {}] = [",
+            upper,
+            list.len()
+        )
+        .unwrap();
+        write_array(&mut code, &list, 8, |value| format!("{},", value));
+        writeln!(code, "];").unwrap();
+        writeln!(code).unwrap();
+        (
+            format!(
+                "&{}_UNICODE_LIST as *const [u32; {}] as *const u32",
+                upper,
+                list.len()
+            ),
+            "SPARSE",
+            "SPARSE",
+        )
+    };
+
+    writeln!(
+        code,
+        "static mut {}_FONT: ::lvgl::RawFont = ::lvgl::RawFont {{",
+        upper
+    )
+    .unwrap();
+    writeln!(code, "    unicode_first: {},", first).unwrap();
+    writeln!(code, "    unicode_last: {},", last).unwrap();
+    writeln!(
+        code,
+        "    glyph_bitmap: &{}_BITMAP as *const [u8; {}] as *const u8,",
+        upper,
+        bitmap.len()
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "    glyph_dsc: &{}_GLYPH_DSC as *const [u32; {}] as *const u32,",
+        upper,
+        glyph_dsc.len()
+    )
+    .unwrap();
+    writeln!(code, "    unicode_list: {},", unicode_list).unwrap();
+    writeln!(
+        code,
+        "    get_bitmap: ::lvgl::RawFont::GET_BITMAP_{},",
+        get_bitmap
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "    get_width: ::lvgl::RawFont::GET_WIDTH_{},",
+        get_width
+    )
+    .unwrap();
+    writeln!(code, "    next_page: ::core::ptr::null_mut(),").unwrap();
+    writeln!(
+        code,
+        "    props: {},",
+        u32::from(font.height) | u32::from(bpp) << 8 | u32::from(monospace) << 12
+    )
+    .unwrap();
+    writeln!(code, "    glyph_cnt: {},", glyphs.len()).unwrap();
+    writeln!(code, "}};").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(
+        code,
+        "/// Get the {} font, converted from {}.",
+        name, description
+    )
+    .unwrap();
+    writeln!(code, "pub fn {}() -> ::lvgl::Font {{", name).unwrap();
+    writeln!(
+        code,
+        "    unsafe {{ ::lvgl::Font::from_raw(&mut {}_FONT) }}",
+        upper
+    )
+    .unwrap();
+    writeln!(code, "}}").unwrap();
+    Ok(code)
+}
+
+/// Append the pixels of a glyph, quantized to `bpp` bits. Each row starts on
+/// a byte boundary, with the leftmost pixel in the most significant bits.
+fn pack(glyph: &Glyph, bpp: u8, bitmap: &mut Vec<u8>) {
+    if glyph.width == 0 {
+        return;
+    }
+    let max = (1u32 << bpp) - 1;
+    for row in glyph.pixels.chunks(glyph.width as usize) {
+        let mut byte = 0u32;
+        let mut bits = 0;
+        for &pixel in row {
+            let level = (u32::from(pixel) * max + 127) / 255;
+            byte |= level << (8 - u32::from(bpp) - bits);
+            bits += u32::from(bpp);
+            if bits == 8 {
+                bitmap.push(byte as u8);
+                byte = 0;
+                bits = 0;
+            }
+        }
+        if bits > 0 {
+            bitmap.push(byte as u8);
+        }
+    }
+}
+
+fn write_array<T: Copy>(code: &mut String, values: &[T], per_line: usize, f: impl Fn(T) -> String) {
+    for line in values.chunks(per_line) {
+        let line: Vec<String> = line.iter().map(|&value| f(value)).collect();
+        writeln!(code, "    {}", line.join(" ")).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyph(letter: u32, width: u8, pixels: &[u8]) -> Glyph {
+        Glyph {
+            letter,
+            width,
+            pixels: pixels.to_vec(),
+        }
+    }
+
+    fn packed(glyph: &Glyph, bpp: u8) -> Vec<u8> {
+        let mut bitmap = Vec::new();
+        pack(glyph, bpp, &mut bitmap);
+        bitmap
+    }
+
+    #[test]
+    fn pack_1_bpp_pads_each_row() {
+        let pixels = [
+            0xff, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, //
+            0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80,
+        ];
+        assert_eq!(packed(&glyph(65, 10, &pixels), 1), [0xa0, 0xc0, 0x40, 0x40]);
+    }
+
+    #[test]
+    fn pack_2_bpp_pads_each_row() {
+        let pixels = [0xff, 0x55, 0xaa, 0x00, 0x00, 0xff];
+        assert_eq!(packed(&glyph(65, 3, &pixels), 2), [0xd8, 0x0c]);
+    }
+
+    #[test]
+    fn pack_4_bpp_pads_each_row() {
+        let pixels = [0xff, 0x00, 0x11];
+        assert_eq!(packed(&glyph(65, 3, &pixels), 4), [0xf0, 0x10]);
+    }
+
+    #[test]
+    fn pack_8_bpp_keeps_the_coverage() {
+        let pixels = [0x01, 0x80, 0xff];
+        assert_eq!(packed(&glyph(65, 3, &pixels), 8), pixels);
+    }
+
+    #[test]
+    fn pack_skips_empty_glyphs() {
+        assert!(packed(&glyph(65, 0, &[]), 4).is_empty());
+    }
+
+    #[test]
+    fn generate_continuous_fills_the_missing_letters() {
+        let font = Rasterized {
+            height: 1,
+            glyphs: vec![glyph(65, 1, &[0xff]), glyph(67, 1, &[0xff])],
+        };
+        let code = generate("test", "test", &font, &[65, 66, 67], 1, 0).unwrap();
+        assert!(code.contains("GET_BITMAP_CONTINUOUS"));
+        assert!(code.contains("unicode_list: ::core::ptr::null(),"));
+        assert!(code.contains("glyph_cnt: 3,"));
+        assert!(!code.contains("TEST_UNICODE_LIST"));
+    }
+
+    #[test]
+    fn generate_sparse_lists_the_letters_found() {
+        let font = Rasterized {
+            height: 1,
+            glyphs: vec![glyph(65, 1, &[0xff]), glyph(67, 1, &[0xff])],
+        };
+        let code = generate("test", "test", &font, &[65, 67], 1, 0).unwrap();
+        assert!(code.contains("GET_BITMAP_SPARSE"));
+        assert!(code.contains("static TEST_UNICODE_LIST: [u32; 3] = [\n    65, 67, 0,\n];"));
+        assert!(code.contains("glyph_cnt: 2,"));
+    }
+
+    #[test]
+    fn generate_writes_the_properties() {
+        let font = Rasterized {
+            height: 12,
+            glyphs: vec![glyph(65, 1, &[0xff; 12])],
+        };
+        let code = generate("test", "test", &font, &[65], 4, 7).unwrap();
+        assert!(code.contains(&format!("props: {},", 12 | 4 << 8 | 7 << 12)));
+    }
+
+    #[test]
+    fn generate_rejects_bitmaps_beyond_the_glyph_index() {
+        // 8 bpp glyphs of 255 * 255 pixels overflow the 24 bits index after
+        // 259 glyphs.
+        let letters: Vec<u32> = (1..=260).collect();
+        let font = Rasterized {
+            height: 255,
+            glyphs: letters
+                .iter()
+                .map(|&letter| glyph(letter, 255, &[0xff; 255 * 255]))
+                .collect(),
+        };
+        match generate("test", "test", &font, &letters, 8, 0) {
+            Err(Error::Options(_)) => {}
+            _ => panic!("Expected the bitmap to be too large"),
+        }
+        assert!(generate("test", "test", &font, &letters[..259], 8, 0).is_ok());
+    }
+}
@@ -0,0 +1,41 @@
+//! Converter of TrueType and BDF fonts into lvgl fonts, to be called from
+//! build scripts.
+//!
+//! The generated file defines a function returning the `lvgl::Font`, named
+//! after the font:
+//!
+//! ```ignore
+//! // build.rs
+//! let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+//! FontConverter::ttf("brand_20", "fonts/Brand.ttf", 20)
+//!     .bpp(4)
+//!     .range(' ', '~')
+//!     .write(out_dir.join("brand_20.rs"))?;
+//!
+//! // src/fonts.rs
+//! include!(concat!(env!("OUT_DIR"), "/brand_20.rs"));
+//! ```
+
+mod bdf;
+mod converter;
+mod error;
+mod generate;
+#[cfg(feature = "ttf")]
+mod ttf;
+
+pub use self::converter::*;
+pub use self::error::*;
+
+/// A glyph rasterized at the height of its font, with a coverage from 0 to
+/// 255 for each pixel.
+struct Glyph {
+    letter: u32,
+    width: u8,
+    pixels: Vec<u8>,
+}
+
+/// The glyphs found in a font, sorted by letter.
+struct Rasterized {
+    height: u8,
+    glyphs: Vec<Glyph>,
+}
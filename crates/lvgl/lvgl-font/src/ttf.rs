@@ -0,0 +1,66 @@
+use crate::error::Error;
+use crate::{Glyph, Rasterized};
+
+use rusttype::{point, Font, GlyphId, Scale};
+use std::char;
+
+/// Rasterize the glyphs of a TrueType font for the given sorted letters. The
+/// glyphs are as high as the ascent and the descent of the font at `size`
+/// pixels, and as wide as their rounded advance.
+pub(crate) fn rasterize(data: Vec<u8>, size: u8, letters: &[u32]) -> Result<Rasterized, Error> {
+    let font = Font::from_bytes(data).map_err(|err| Error::Font(err.to_string()))?;
+    let scale = Scale::uniform(f32::from(size));
+    let v_metrics = font.v_metrics(scale);
+    let ascent = v_metrics.ascent.ceil();
+    let height = ascent - v_metrics.descent.floor();
+    if height <= 0.0 || height > f32::from(u8::max_value()) {
+        return Err(Error::Options(format!("Unsupported height {}", height)));
+    }
+    let height = height as i32;
+
+    let mut glyphs = Vec::new();
+    for &letter in letters {
+        let c = match char::from_u32(letter) {
+            Some(c) => c,
+            None => continue,
+        };
+        let glyph = font.glyph(c);
+        if glyph.id() == GlyphId(0) {
+            continue;
+        }
+
+        let glyph = glyph.scaled(scale);
+        let advance = glyph.h_metrics().advance_width.round();
+        if advance < 0.0 || advance > f32::from(u8::max_value()) {
+            return Err(Error::Options(format!(
+                "Unsupported width {} of letter {:?}",
+                advance, c
+            )));
+        }
+        let width = advance as i32;
+
+        let glyph = glyph.positioned(point(0.0, ascent));
+        let mut pixels = vec![0; (width * height) as usize];
+        if let Some(bb) = glyph.pixel_bounding_box() {
+            glyph.draw(|x, y, coverage| {
+                let x = bb.min.x + x as i32;
+                let y = bb.min.y + y as i32;
+                if x >= 0 && x < width && y >= 0 && y < height {
+                    let pixel = &mut pixels[(y * width + x) as usize];
+                    *pixel = (*pixel).max((coverage * 255.0).round() as u8);
+                }
+            });
+        }
+
+        glyphs.push(Glyph {
+            letter,
+            width: width as u8,
+            pixels,
+        });
+    }
+
+    Ok(Rasterized {
+        height: height as u8,
+        glyphs,
+    })
+}
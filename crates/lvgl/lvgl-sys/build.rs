@@ -43,6 +43,19 @@ fn main() -> Result<(), Box<Error>> {
         _ => {}
     }
 
+    // Options enabled by Cargo features, overriding the defaults of lv_conf.h.
+    let options: Vec<&str> = FEATURE_OPTIONS
+        .iter()
+        .filter(|(feature, _)| env::var_os(feature).is_some())
+        .map(|&(_, option)| option)
+        .collect();
+    let defines: Vec<String> = options
+        .iter()
+        .map(|option| format!("-D{}=1", option))
+        .collect();
+    let cflags = env::var("CFLAGS").unwrap_or_default();
+    env::set_var("CFLAGS", format!("{} {}", cflags, defines.join(" ")));
+
     let cargo_manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?);
     let _ = Command::new("make")
         .current_dir(&cargo_manifest_dir)
@@ -56,6 +69,7 @@ fn main() -> Result<(), Box<Error>> {
     builder = builder.header("lvgl.h");
     builder = builder.use_core();
     builder = builder.ctypes_prefix("ffi");
+    builder = builder.clang_args(&defines);
     if target == "thumbv7em-none-eabi" || target == "thumbv7em-none-eabihf" {
         builder = builder.clang_arg("--target=thumbv7em-none-eabihf");
         builder = builder.clang_arg("--sysroot=/usr/local/opt/arm-none-eabi-gcc/gcc/arm-none-eabi");
@@ -72,10 +86,50 @@ fn main() -> Result<(), Box<Error>> {
 
     // Make sure these options are synchronized with lv_conf.h.
     println!("cargo:lv_color_depth=16");
+    println!("cargo:use_lv_animation=enabled");
+    println!("cargo:use_lv_arc=enabled");
+    println!("cargo:use_lv_bar=enabled");
+    println!("cargo:use_lv_btn=enabled");
+    println!("cargo:use_lv_btnm=enabled");
+    println!("cargo:use_lv_calendar=enabled");
+    println!("cargo:use_lv_cb=enabled");
+    println!("cargo:use_lv_chart=enabled");
+    println!("cargo:use_lv_cont=enabled");
+    println!("cargo:use_lv_ddlist=enabled");
     println!("cargo:use_lv_gauge=enabled");
+    println!("cargo:use_lv_group=enabled");
     println!("cargo:use_lv_img=enabled");
+    println!("cargo:use_lv_imgbtn=enabled");
+    println!("cargo:use_lv_kb=enabled");
     println!("cargo:use_lv_label=enabled");
+    println!("cargo:use_lv_led=enabled");
+    println!("cargo:use_lv_line=enabled");
+    println!("cargo:use_lv_list=enabled");
     println!("cargo:use_lv_lmeter=enabled");
+    println!("cargo:use_lv_mbox=enabled");
+    println!("cargo:use_lv_page=enabled");
+    println!("cargo:use_lv_preload=enabled");
+    println!("cargo:use_lv_roller=enabled");
+    println!("cargo:use_lv_slider=enabled");
+    println!("cargo:use_lv_sw=enabled");
+    println!("cargo:use_lv_ta=enabled");
+    println!("cargo:use_lv_tabview=enabled");
+    println!("cargo:use_lv_win=enabled");
+    for option in options {
+        println!("cargo:{}=enabled", option.to_lowercase());
+    }
 
     Ok(())
 }
+
+/// Options of lv_conf.h enabled by the Cargo features.
+const FEATURE_OPTIONS: &[(&str, &str)] = &[
+    ("CARGO_FEATURE_THEME_ALIEN", "USE_LV_THEME_ALIEN"),
+    ("CARGO_FEATURE_THEME_DEFAULT", "USE_LV_THEME_DEFAULT"),
+    ("CARGO_FEATURE_THEME_LIVE_UPDATE", "LV_THEME_LIVE_UPDATE"),
+    ("CARGO_FEATURE_THEME_MATERIAL", "USE_LV_THEME_MATERIAL"),
+    ("CARGO_FEATURE_THEME_MONO", "USE_LV_THEME_MONO"),
+    ("CARGO_FEATURE_THEME_NEMO", "USE_LV_THEME_NEMO"),
+    ("CARGO_FEATURE_THEME_NIGHT", "USE_LV_THEME_NIGHT"),
+    ("CARGO_FEATURE_THEME_ZEN", "USE_LV_THEME_ZEN"),
+];
@@ -120,16 +120,34 @@
 /*================
  *  THEME USAGE
  *================*/
+/* The themes and the live update can also be enabled by the Cargo features
+ * of lvgl-sys, which define these options on the command line. */
+#ifndef LV_THEME_LIVE_UPDATE
 #define LV_THEME_LIVE_UPDATE    0       /*1: Allow theme switching at run time. Uses 8..10 kB of RAM*/
+#endif
 
 #define USE_LV_THEME_TEMPL      0       /*Just for test*/
+#ifndef USE_LV_THEME_DEFAULT
 #define USE_LV_THEME_DEFAULT    0       /*Built mainly from the built-in styles. Consumes very few RAM*/
+#endif
+#ifndef USE_LV_THEME_ALIEN
 #define USE_LV_THEME_ALIEN      0       /*Dark futuristic theme*/
+#endif
+#ifndef USE_LV_THEME_NIGHT
 #define USE_LV_THEME_NIGHT      0       /*Dark elegant theme*/
+#endif
+#ifndef USE_LV_THEME_MONO
 #define USE_LV_THEME_MONO       0       /*Mono color theme for monochrome displays*/
+#endif
+#ifndef USE_LV_THEME_MATERIAL
 #define USE_LV_THEME_MATERIAL   0       /*Flat theme with bold colors and light shadows*/
+#endif
+#ifndef USE_LV_THEME_ZEN
 #define USE_LV_THEME_ZEN        0       /*Peaceful, mainly light theme */
+#endif
+#ifndef USE_LV_THEME_NEMO
 #define USE_LV_THEME_NEMO       0       /*Water-like theme based on the movie "Finding Nemo"*/
+#endif
 
 /*==================
  *    FONT USAGE
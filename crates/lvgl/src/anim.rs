@@ -0,0 +1,336 @@
+use crate::callback;
+use crate::obj::ObjT;
+use crate::style::Style;
+
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use lvgl_sys::*;
+
+/// Default duration of an animation in milliseconds.
+const DEFAULT_TIME: u16 = 500;
+
+/// Timing function of an animation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimPath {
+    /// Constant speed.
+    Linear,
+    /// Accelerate at the start and decelerate at the end.
+    EaseInOut,
+    /// Go past the end value and come back.
+    Overshoot,
+    /// Bounce back from the end value a few times.
+    Bounce,
+    /// Jump to the end value at the end.
+    Step,
+}
+
+impl AnimPath {
+    fn to_lv_path(self) -> lv_anim_path_t {
+        match self {
+            AnimPath::Linear => Some(lv_anim_path_linear),
+            AnimPath::EaseInOut => Some(lv_anim_path_ease_in_out),
+            AnimPath::Overshoot => Some(lv_anim_path_overshoot),
+            AnimPath::Bounce => Some(lv_anim_path_bounce),
+            AnimPath::Step => Some(lv_anim_path_step),
+        }
+    }
+}
+
+/// Builder of an animation of an object.
+///
+/// Closures passed as the setter or the end callback must not capture any
+/// variables, because lvgl gives no way to release them.
+pub struct Anim<T: ObjT> {
+    anim: lv_anim_t,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ObjT> Anim<T> {
+    /// Start building an animation of `obj`. It runs along a linear path for
+    /// 500 ms by default.
+    #[inline]
+    pub fn new(obj: &T) -> Self {
+        let mut anim: lv_anim_t = unsafe { mem::zeroed() };
+        anim.var = unsafe { obj.as_lv_obj() } as *mut c_void;
+        anim.path = AnimPath::Linear.to_lv_path();
+        anim.time = DEFAULT_TIME;
+        Anim {
+            anim,
+            _marker: PhantomData,
+        }
+    }
+
+    // Animated values.
+
+    /// Animate the x coordinate of the object.
+    #[inline]
+    pub fn x(self, start: lv_coord_t, end: lv_coord_t) -> Self {
+        self.exec_fp(start as i32, end as i32, Some(set_x))
+    }
+
+    /// Animate the y coordinate of the object.
+    #[inline]
+    pub fn y(self, start: lv_coord_t, end: lv_coord_t) -> Self {
+        self.exec_fp(start as i32, end as i32, Some(set_y))
+    }
+
+    /// Animate the width of the object.
+    #[inline]
+    pub fn width(self, start: lv_coord_t, end: lv_coord_t) -> Self {
+        self.exec_fp(start as i32, end as i32, Some(set_width))
+    }
+
+    /// Animate the height of the object.
+    #[inline]
+    pub fn height(self, start: lv_coord_t, end: lv_coord_t) -> Self {
+        self.exec_fp(start as i32, end as i32, Some(set_height))
+    }
+
+    /// Animate the opacity of the object and its children.
+    #[inline]
+    pub fn opa(self, start: lv_opa_t, end: lv_opa_t) -> Self {
+        unsafe {
+            lv_obj_set_opa_scale_enable(self.anim.var as *mut lv_obj_t, true);
+        }
+        self.exec_fp(start as i32, end as i32, Some(set_opa))
+    }
+
+    /// Animate an arbitrary property of the object with a setter closure.
+    #[inline]
+    pub fn exec<F>(self, start: i32, end: i32, setter: F) -> Self
+    where
+        F: Fn(&mut T, i32) + Copy + 'static,
+    {
+        callback::assert_zero_sized(&setter);
+        self.exec_fp(start, end, Some(exec_trampoline::<T, F>))
+    }
+
+    #[inline]
+    fn exec_fp(mut self, start: i32, end: i32, fp: lv_anim_fp_t) -> Self {
+        self.anim.start = start;
+        self.anim.end = end;
+        self.anim.fp = fp;
+        self
+    }
+
+    // Timing.
+
+    /// Set the duration of the animation in milliseconds.
+    #[inline]
+    pub fn time(mut self, time: u16) -> Self {
+        self.anim.time = time;
+        self
+    }
+
+    /// Set the delay before the animation starts in milliseconds, up to
+    /// 32767.
+    #[inline]
+    pub fn delay(mut self, delay: u16) -> Self {
+        assert!(
+            delay <= i16::max_value() as u16,
+            "Animation delay out of range"
+        );
+        // A negative time means the animation is waiting.
+        self.anim.act_time = -(delay as i16);
+        self
+    }
+
+    /// Set the path of the animation.
+    #[inline]
+    pub fn path(mut self, path: AnimPath) -> Self {
+        self.anim.path = path.to_lv_path();
+        self
+    }
+
+    /// Play the animation backward after it has finished, after waiting
+    /// `pause` milliseconds.
+    #[inline]
+    pub fn playback(mut self, pause: u16) -> Self {
+        self.anim.set_playback(1);
+        self.anim.playback_pause = pause;
+        self
+    }
+
+    /// Repeat the animation forever, waiting `pause` milliseconds between the
+    /// repetitions.
+    #[inline]
+    pub fn repeat(mut self, pause: u16) -> Self {
+        self.anim.set_repeat(1);
+        self.anim.repeat_pause = pause;
+        self
+    }
+
+    /// Call `end_cb` when the animation is ready.
+    #[inline]
+    pub fn on_end<F>(mut self, end_cb: F) -> Self
+    where
+        F: Fn(&mut T) + Copy + 'static,
+    {
+        callback::assert_zero_sized(&end_cb);
+        self.anim.end_cb = Some(end_trampoline::<T, F>);
+        self
+    }
+
+    /// Start the animation. lvgl keeps a copy of it, so the builder can be
+    /// dropped.
+    #[inline]
+    pub fn start(mut self) {
+        unsafe {
+            lv_anim_create(&mut self.anim);
+        }
+    }
+}
+
+/// Builder of an animation of a style between two other styles. The objects
+/// using the animated style are refreshed at each step.
+///
+/// The end callback must not capture any variables, because lvgl gives no way
+/// to release it.
+pub struct StyleAnim<'a> {
+    anim: lv_style_anim_t,
+    _marker: PhantomData<&'a Style>,
+}
+
+impl<'a> StyleAnim<'a> {
+    /// Start building an animation of `style` from `start` to `end`. Both are
+    /// copied when the animation starts. It runs for 500 ms by default.
+    #[inline]
+    pub fn new(style: &'static Style, start: &'a Style, end: &'a Style) -> Self {
+        let mut anim: lv_style_anim_t = unsafe { mem::zeroed() };
+        anim.style_anim = style.as_lv_style();
+        anim.style_start = start.as_lv_style();
+        anim.style_end = end.as_lv_style();
+        anim.time = DEFAULT_TIME as i16;
+        StyleAnim {
+            anim,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set the duration of the animation in milliseconds, up to 32767.
+    #[inline]
+    pub fn time(mut self, time: u16) -> Self {
+        assert!(
+            time <= i16::max_value() as u16,
+            "Animation time out of range"
+        );
+        self.anim.time = time as i16;
+        self
+    }
+
+    /// Set the delay before the animation starts in milliseconds, up to
+    /// 32767.
+    #[inline]
+    pub fn delay(mut self, delay: u16) -> Self {
+        assert!(
+            delay <= i16::max_value() as u16,
+            "Animation delay out of range"
+        );
+        // A negative time means the animation is waiting.
+        self.anim.act_time = -(delay as i16);
+        self
+    }
+
+    /// Animate back to the start style after the end style has been reached,
+    /// after waiting `pause` milliseconds.
+    #[inline]
+    pub fn playback(mut self, pause: u16) -> Self {
+        self.anim.set_playback(1);
+        self.anim.playback_pause = pause;
+        self
+    }
+
+    /// Call `end_cb` when the animation is ready.
+    #[inline]
+    pub fn on_end<F>(mut self, end_cb: F) -> Self
+    where
+        F: Fn() + Copy + 'static,
+    {
+        callback::assert_zero_sized(&end_cb);
+        self.anim.end_cb = Some(style_end_trampoline::<F>);
+        self
+    }
+
+    /// Start the animation. It deletes itself when it is ready.
+    #[inline]
+    pub fn start(mut self) {
+        unsafe {
+            lv_style_anim_create(&mut self.anim);
+        }
+    }
+
+    /// Start the animation and repeat it, waiting `pause` milliseconds between
+    /// the repetitions, until the returned handle is dropped. The end callback
+    /// is never called.
+    #[inline]
+    pub fn start_repeat(mut self, pause: u16) -> RepeatingStyleAnim {
+        self.anim.set_repeat(1);
+        self.anim.repeat_pause = pause;
+        unsafe { RepeatingStyleAnim(lv_style_anim_create(&mut self.anim)) }
+    }
+}
+
+/// A repeating style animation, stopped when dropped. The animated style
+/// keeps its last value.
+pub struct RepeatingStyleAnim(*mut c_void);
+
+impl Drop for RepeatingStyleAnim {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            // NOTE: lvgl only frees the descriptor of the animation when it
+            // ends, which a repeating animation never does.
+            lv_anim_del(self.0, None);
+            lv_mem_free(self.0);
+        }
+    }
+}
+
+unsafe extern "C" fn set_x(obj: *mut c_void, value: i32) {
+    lv_obj_set_x(obj as *mut lv_obj_t, value as lv_coord_t);
+}
+
+unsafe extern "C" fn set_y(obj: *mut c_void, value: i32) {
+    lv_obj_set_y(obj as *mut lv_obj_t, value as lv_coord_t);
+}
+
+unsafe extern "C" fn set_width(obj: *mut c_void, value: i32) {
+    lv_obj_set_width(obj as *mut lv_obj_t, value as lv_coord_t);
+}
+
+unsafe extern "C" fn set_height(obj: *mut c_void, value: i32) {
+    lv_obj_set_height(obj as *mut lv_obj_t, value as lv_coord_t);
+}
+
+unsafe extern "C" fn set_opa(obj: *mut c_void, value: i32) {
+    lv_obj_set_opa_scale(obj as *mut lv_obj_t, value as lv_opa_t);
+}
+
+unsafe extern "C" fn exec_trampoline<T, F>(obj: *mut c_void, value: i32)
+where
+    T: ObjT,
+    F: Fn(&mut T, i32) + Copy,
+{
+    let setter: F = callback::recreate();
+    let mut obj = ManuallyDrop::new(T::from_lv_obj(obj as *mut lv_obj_t));
+    setter(&mut obj, value);
+}
+
+unsafe extern "C" fn end_trampoline<T, F>(obj: *mut c_void)
+where
+    T: ObjT,
+    F: Fn(&mut T) + Copy,
+{
+    let end_cb: F = callback::recreate();
+    let mut obj = ManuallyDrop::new(T::from_lv_obj(obj as *mut lv_obj_t));
+    end_cb(&mut obj);
+}
+
+unsafe extern "C" fn style_end_trampoline<F>(_: *mut c_void)
+where
+    F: Fn() + Copy,
+{
+    let end_cb: F = callback::recreate();
+    end_cb();
+}
@@ -0,0 +1,23 @@
+//! Support for Rust closures as lvgl callbacks which carry no user data.
+//!
+//! A closure which captures nothing is zero-sized, so its type alone is
+//! enough to call it: a generic `extern "C"` trampoline is instantiated for
+//! each closure type and recreates the closure when it is called.
+
+use core::mem;
+
+/// Check that a closure captures nothing and can be recreated by `recreate`.
+#[inline]
+pub(crate) fn assert_zero_sized<F: Copy>(_: &F) {
+    assert!(
+        mem::size_of::<F>() == 0,
+        "lvgl callbacks must not capture any variables"
+    );
+}
+
+/// Recreate a closure which has been checked with `assert_zero_sized`.
+#[inline]
+pub(crate) unsafe fn recreate<F: Copy>() -> F {
+    debug_assert!(mem::size_of::<F>() == 0);
+    mem::zeroed()
+}
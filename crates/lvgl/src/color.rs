@@ -42,6 +42,28 @@ impl Color {
             value: (((r >> 3) as u16) << 11) | (((g >> 2) as u16) << 5) | ((b >> 3) as u16),
         }
     }
+
+    /// Convert the color to a 32-bit ARGB8888 value.
+    #[inline]
+    #[cfg(all(lv_color_depth = "1", target_endian = "little"))]
+    pub fn to_u32(self) -> u32 {
+        if unsafe { self.value } == 0 {
+            0xFF00_0000
+        } else {
+            0xFFFF_FFFF
+        }
+    }
+
+    /// Convert the color to a 32-bit ARGB8888 value.
+    #[inline]
+    #[cfg(all(lv_color_depth = "16", target_endian = "little"))]
+    pub fn to_u32(self) -> u32 {
+        let value = unsafe { self.value } as u32;
+        let r = ((value >> 11) & 0x1F) << 3;
+        let g = ((value >> 5) & 0x3F) << 2;
+        let b = (value & 0x1F) << 3;
+        0xFF00_0000 | (r << 16) | (g << 8) | b
+    }
 }
 
 impl From<lv_color_t> for Color {
@@ -0,0 +1,159 @@
+use crate::color::Color;
+
+use core::mem;
+use core::slice;
+use lvgl_sys::*;
+
+/// A point on the display.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Point {
+    pub x: lv_coord_t,
+    pub y: lv_coord_t,
+}
+
+impl Point {
+    #[inline]
+    pub const fn new(x: lv_coord_t, y: lv_coord_t) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<lv_point_t> for Point {
+    #[inline]
+    fn from(point: lv_point_t) -> Self {
+        Point::new(point.x, point.y)
+    }
+}
+
+impl From<Point> for lv_point_t {
+    #[inline]
+    fn from(point: Point) -> Self {
+        lv_point_t {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+/// An area of the display with inclusive coordinates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Area {
+    pub x1: i32,
+    pub y1: i32,
+    pub x2: i32,
+    pub y2: i32,
+}
+
+impl Area {
+    /// Get the width of the area.
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.x2 - self.x1 + 1
+    }
+
+    /// Get the height of the area.
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.y2 - self.y1 + 1
+    }
+
+    /// Check whether the area contains no pixels.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.x2 < self.x1 || self.y2 < self.y1
+    }
+
+    /// Get the number of pixels in the area.
+    #[inline]
+    pub fn len(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.width() as usize * self.height() as usize
+        }
+    }
+}
+
+/// The display contract lvgl draws through.
+pub trait DisplayDriver {
+    /// Write `colors`, which cover `area` in row order, to the display.
+    /// `Display::flush_ready` must be called when finished, which may also
+    /// happen later, e.g. from a DMA interrupt.
+    fn flush(&mut self, area: Area, colors: &[Color]);
+
+    /// Fill `area` with `color`. Only used when lvgl draws without a virtual
+    /// display buffer.
+    #[inline]
+    fn fill(&mut self, _area: Area, _color: Color) {}
+
+    /// Write `colors`, which cover `area` in row order, to the display. Only
+    /// used when lvgl draws without a virtual display buffer.
+    #[inline]
+    fn map(&mut self, _area: Area, _colors: &[Color]) {}
+}
+
+static mut DISPLAY_DRIVER: Option<&'static mut dyn DisplayDriver> = None;
+
+pub struct Display;
+
+impl Display {
+    /// Register the display driver. Only one display is supported, a second
+    /// registration replaces the driver of the first one.
+    ///
+    /// Returns `false` if there is not enough memory.
+    #[inline]
+    pub fn register(driver: &'static mut dyn DisplayDriver) -> bool {
+        unsafe {
+            let registered = DISPLAY_DRIVER.is_some();
+            DISPLAY_DRIVER = Some(driver);
+            if registered {
+                return true;
+            }
+
+            // lvgl keeps a copy of the driver.
+            let mut display_driver: lv_disp_drv_t = mem::zeroed();
+            lv_disp_drv_init(&mut display_driver);
+            display_driver.disp_flush = Some(disp_flush);
+            display_driver.disp_fill = Some(disp_fill);
+            display_driver.disp_map = Some(disp_map);
+            !lv_disp_drv_register(&mut display_driver).is_null()
+        }
+    }
+
+    /// Let lvgl know that the display driver has finished flushing.
+    #[inline]
+    pub fn flush_ready() {
+        unsafe {
+            lv_flush_ready();
+        }
+    }
+}
+
+unsafe fn colors<'a>(area: Area, color_p: *const lv_color_t) -> &'a [Color] {
+    if color_p.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(color_p as *const Color, area.len())
+    }
+}
+
+unsafe extern "C" fn disp_flush(x1: i32, y1: i32, x2: i32, y2: i32, color_p: *const lv_color_t) {
+    let area = Area { x1, y1, x2, y2 };
+    if let Some(ref mut driver) = DISPLAY_DRIVER {
+        driver.flush(area, colors(area, color_p));
+    }
+}
+
+unsafe extern "C" fn disp_fill(x1: i32, y1: i32, x2: i32, y2: i32, color: lv_color_t) {
+    if let Some(ref mut driver) = DISPLAY_DRIVER {
+        driver.fill(Area { x1, y1, x2, y2 }, Color::from(color));
+    }
+}
+
+unsafe extern "C" fn disp_map(x1: i32, y1: i32, x2: i32, y2: i32, color_p: *const lv_color_t) {
+    let area = Area { x1, y1, x2, y2 };
+    if let Some(ref mut driver) = DISPLAY_DRIVER {
+        driver.map(area, colors(area, color_p));
+    }
+}
@@ -0,0 +1,266 @@
+use crate::obj::ObjT;
+
+use core::ffi::c_void;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+use lvgl_sys::*;
+
+/// An input event delivered to the event handler of an object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// The object has been pressed.
+    Pressed,
+    /// The input device has slid off the object while pressing it.
+    PressLost,
+    /// The object has been released after a press.
+    Released,
+    /// The object has been pressed for a longer time.
+    LongPress,
+    /// Sent periodically after `LongPress` while the object is still pressed.
+    LongPressRepeat,
+    /// The object has been released without being dragged.
+    Clicked,
+    /// The value of the object (e.g. a slider or a switch) has been changed
+    /// by the user.
+    ValueChanged,
+    /// The user has confirmed the input (e.g. the ok key of a keyboard).
+    Apply,
+    /// The user has cancelled the input (e.g. the hide key of a keyboard).
+    Cancel,
+}
+
+/// Type-erased header of the event handler record stored in the free pointer
+/// of an object.
+#[repr(C)]
+struct Header {
+    /// The signal function which was replaced by `event_signal`.
+    ancestor: lv_signal_func_t,
+    /// Call the handler.
+    call: unsafe fn(*mut Header, *mut lv_obj_t, Event),
+    /// Drop the handler and free the record.
+    release: unsafe fn(*mut Header),
+    /// Number of running calls of the handler, more than one if an event is
+    /// dispatched from within the handler.
+    depth: u8,
+    /// Set if the handler has been replaced or cleared while running.
+    detached: bool,
+    /// The record replacing this one once the handler returns, or null if
+    /// the handler is cleared.
+    next: *mut Header,
+    /// Set if the object has been deleted while the handler is running.
+    deleted: bool,
+}
+
+#[repr(C)]
+struct Record<F> {
+    header: Header,
+    handler: F,
+}
+
+/// Attach `handler` to `obj`, replacing the previous handler if there is one.
+///
+/// A running handler is replaced once it returns.
+pub(crate) unsafe fn set_handler<T, F>(obj: *mut lv_obj_t, handler: F)
+where
+    T: ObjT,
+    F: FnMut(&mut T, Event) + 'static,
+{
+    // lvgl allocates with pointer alignment.
+    assert!(mem::align_of::<Record<F>>() <= mem::align_of::<usize>());
+
+    let current = get_header(obj);
+    let ancestor = match current {
+        Some(header) => (*header).ancestor,
+        None => lv_obj_get_signal_func(obj),
+    };
+
+    let record = lv_mem_alloc(mem::size_of::<Record<F>>() as u32) as *mut Record<F>;
+    assert!(!record.is_null(), "Out of lvgl memory");
+    ptr::write(
+        record,
+        Record {
+            header: Header {
+                ancestor,
+                call: call::<T, F>,
+                release: release::<F>,
+                depth: 0,
+                detached: false,
+                next: ptr::null_mut(),
+                deleted: false,
+            },
+            handler,
+        },
+    );
+    let record = record as *mut Header;
+
+    match current {
+        Some(header) if (*header).depth > 0 => detach(header, record),
+        Some(header) => {
+            ((*header).release)(header);
+            lv_obj_set_free_ptr(obj, record as *mut c_void);
+        }
+        None => {
+            lv_obj_set_free_ptr(obj, record as *mut c_void);
+            lv_obj_set_signal_func(obj, Some(event_signal));
+        }
+    }
+}
+
+/// Detach the event handler of `obj` and restore its original signal
+/// function.
+///
+/// A running handler is detached once it returns.
+pub(crate) unsafe fn clear_handler(obj: *mut lv_obj_t) {
+    if let Some(header) = get_header(obj) {
+        if (*header).depth > 0 {
+            detach(header, ptr::null_mut());
+        } else {
+            lv_obj_set_signal_func(obj, (*header).ancestor);
+            lv_obj_set_free_ptr(obj, ptr::null_mut());
+            ((*header).release)(header);
+        }
+    }
+}
+
+/// Deliver an event to the handler of `obj`.
+///
+/// Returns `LV_RES_INV` if the object has been deleted by the handler.
+pub(crate) unsafe fn dispatch(obj: *mut lv_obj_t, event: Event) -> lv_res_t {
+    let header = match get_header(obj) {
+        Some(header) => header,
+        None => return LV_RES_OK as lv_res_t,
+    };
+    if (*header).detached {
+        return LV_RES_OK as lv_res_t;
+    }
+
+    (*header).depth += 1;
+    ((*header).call)(header, obj, event);
+    (*header).depth -= 1;
+
+    // The record outlives a deletion or a detach from within the handler
+    // until the outermost call returns.
+    if (*header).depth > 0 {
+        return if (*header).deleted {
+            LV_RES_INV as lv_res_t
+        } else {
+            LV_RES_OK as lv_res_t
+        };
+    }
+    if (*header).deleted {
+        let next = (*header).next;
+        if !next.is_null() {
+            ((*next).release)(next);
+        }
+        ((*header).release)(header);
+        LV_RES_INV as lv_res_t
+    } else if (*header).detached {
+        let next = (*header).next;
+        if next.is_null() {
+            lv_obj_set_signal_func(obj, (*header).ancestor);
+        }
+        lv_obj_set_free_ptr(obj, next as *mut c_void);
+        ((*header).release)(header);
+        LV_RES_OK as lv_res_t
+    } else {
+        LV_RES_OK as lv_res_t
+    }
+}
+
+/// Action callback of the widgets with a value, delivering `ValueChanged` to
+/// the event handler of the object.
+pub(crate) unsafe extern "C" fn value_changed_action(obj: *mut lv_obj_t) -> lv_res_t {
+    dispatch(obj, Event::ValueChanged)
+}
+
+/// Action callback delivering `Apply` to the event handler of the object.
+pub(crate) unsafe extern "C" fn apply_action(obj: *mut lv_obj_t) -> lv_res_t {
+    dispatch(obj, Event::Apply)
+}
+
+/// Action callback delivering `Cancel` to the event handler of the object.
+pub(crate) unsafe extern "C" fn cancel_action(obj: *mut lv_obj_t) -> lv_res_t {
+    dispatch(obj, Event::Cancel)
+}
+
+unsafe fn get_header(obj: *mut lv_obj_t) -> Option<*mut Header> {
+    let signal_func = lv_obj_get_signal_func(obj);
+    let header = lv_obj_get_free_ptr(obj) as *mut Header;
+    let event_signal: lv_signal_func_t = Some(event_signal);
+    if signal_func == event_signal && !header.is_null() {
+        Some(header)
+    } else {
+        None
+    }
+}
+
+/// Mark a running handler to be replaced by `next`, or cleared if `next` is
+/// null, once it returns.
+unsafe fn detach(header: *mut Header, next: *mut Header) {
+    let pending = (*header).next;
+    if !pending.is_null() {
+        ((*pending).release)(pending);
+    }
+    (*header).detached = true;
+    (*header).next = next;
+}
+
+unsafe fn call<T, F>(header: *mut Header, obj: *mut lv_obj_t, event: Event)
+where
+    T: ObjT,
+    F: FnMut(&mut T, Event),
+{
+    let record = header as *mut Record<F>;
+    // The handle is only borrowed, it must not delete the object when dropped.
+    let mut target = ManuallyDrop::new(T::from_lv_obj(obj));
+    ((*record).handler)(&mut target, event);
+}
+
+unsafe fn release<F>(header: *mut Header) {
+    let record = header as *mut Record<F>;
+    ptr::drop_in_place(&mut (*record).handler);
+    lv_mem_free(record as *const c_void);
+}
+
+unsafe extern "C" fn event_signal(
+    obj: *mut lv_obj_t,
+    sign: lv_signal_t,
+    param: *mut c_void,
+) -> lv_res_t {
+    let header = lv_obj_get_free_ptr(obj) as *mut Header;
+
+    // Let the original signal function handle the signal first.
+    if let Some(ancestor) = (*header).ancestor {
+        let res = ancestor(obj, sign, param);
+        if res != LV_RES_OK as lv_res_t {
+            return res;
+        }
+    }
+
+    let event = match sign as u32 {
+        LV_SIGNAL_CLEANUP => {
+            lv_obj_set_free_ptr(obj, ptr::null_mut());
+            if (*header).depth > 0 {
+                (*header).deleted = true;
+            } else {
+                ((*header).release)(header);
+            }
+            return LV_RES_OK as lv_res_t;
+        }
+        LV_SIGNAL_PRESSED => Event::Pressed,
+        LV_SIGNAL_PRESS_LOST => Event::PressLost,
+        LV_SIGNAL_LONG_PRESS => Event::LongPress,
+        LV_SIGNAL_LONG_PRESS_REP => Event::LongPressRepeat,
+        LV_SIGNAL_RELEASED => {
+            // The input device is passed with the press related signals.
+            let dragged = lv_indev_is_dragging(param as *const lv_indev_t);
+            let res = dispatch(obj, Event::Released);
+            if res != LV_RES_OK as lv_res_t || dragged {
+                return res;
+            }
+            Event::Clicked
+        }
+        _ => return LV_RES_OK as lv_res_t,
+    };
+    dispatch(obj, event)
+}
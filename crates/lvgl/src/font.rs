@@ -1,3 +1,4 @@
+use core::mem;
 use lvgl_sys::*;
 
 #[repr(transparent)]
@@ -22,6 +23,15 @@ impl Font {
     }
 }
 
+impl Font {
+    /// Get a font generated at build time by lvgl-font. `font` must point to
+    /// a static, which lvgl changes when the font is extended.
+    #[inline]
+    pub unsafe fn from_raw(font: *mut RawFont) -> Font {
+        Font(font as *const lv_font_t)
+    }
+}
+
 impl From<*const lv_font_t> for Font {
     #[inline]
     fn from(item: *const lv_font_t) -> Self {
@@ -35,3 +45,38 @@ impl From<Font> for *const lv_font_t {
         item.0
     }
 }
+
+type GetBitmap = Option<unsafe extern "C" fn(*const lv_font_t, u32) -> *const u8>;
+type GetWidth = Option<unsafe extern "C" fn(*const lv_font_t, u32) -> i16>;
+
+/// The layout of lv_font_t, filled by the code generated by lvgl-font.
+#[repr(C)]
+pub struct RawFont {
+    pub unicode_first: u32,
+    pub unicode_last: u32,
+    /// The glyphs, each made of rows of pixels starting at a byte boundary.
+    pub glyph_bitmap: *const u8,
+    /// The width of each glyph in the bits 0-7 and the index of its bitmap
+    /// in the bits 8-31.
+    pub glyph_dsc: *const u32,
+    /// The letters of a sparse font, ending with 0.
+    pub unicode_list: *const u32,
+    pub get_bitmap: GetBitmap,
+    pub get_width: GetWidth,
+    pub next_page: *mut lv_font_t,
+    /// The height in pixels in the bits 0-7, the bits per pixel in the bits
+    /// 8-11 and the fixed width of a monospace font in the bits 12-19.
+    pub props: u32,
+    pub glyph_cnt: u16,
+}
+
+impl RawFont {
+    pub const GET_BITMAP_CONTINUOUS: GetBitmap = Some(lv_font_get_bitmap_continuous);
+    pub const GET_WIDTH_CONTINUOUS: GetWidth = Some(lv_font_get_width_continuous);
+    pub const GET_BITMAP_SPARSE: GetBitmap = Some(lv_font_get_bitmap_sparse);
+    pub const GET_WIDTH_SPARSE: GetWidth = Some(lv_font_get_width_sparse);
+}
+
+// The layout of `RawFont` must match lv_font_t.
+#[allow(dead_code)]
+const RAW_FONT_SIZE_CHECK: [(); mem::size_of::<lv_font_t>()] = [(); mem::size_of::<RawFont>()];
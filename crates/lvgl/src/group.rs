@@ -0,0 +1,240 @@
+use crate::callback;
+use crate::obj::{ObjRef, ObjT};
+use crate::style::StyleBuilder;
+
+use core::ptr;
+use lvgl_sys::*;
+
+/// Control keys understood by the objects in a group.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GroupKey {
+    Up = LV_GROUP_KEY_UP,
+    Down = LV_GROUP_KEY_DOWN,
+    Right = LV_GROUP_KEY_RIGHT,
+    Left = LV_GROUP_KEY_LEFT,
+    Esc = LV_GROUP_KEY_ESC,
+    Del = LV_GROUP_KEY_DEL,
+    Backspace = LV_GROUP_KEY_BACKSPACE,
+    Enter = LV_GROUP_KEY_ENTER,
+    Next = LV_GROUP_KEY_NEXT,
+    Prev = LV_GROUP_KEY_PREV,
+    Home = LV_GROUP_KEY_HOME,
+    End = LV_GROUP_KEY_END,
+}
+
+/// A group of objects with one of them focused, for navigating with a
+/// keypad, an encoder or buttons instead of touch.
+///
+/// Closures passed as callbacks must not capture any variables, because lvgl
+/// gives no way to store them.
+///
+/// The group is deleted when it is dropped, the objects are kept and the input
+/// devices stop sending keys to it.
+pub struct Group(*mut lv_group_t);
+
+impl Group {
+    /// Create a new empty group.
+    #[inline]
+    pub fn new() -> Option<Self> {
+        unsafe {
+            let group = lv_group_create();
+            if group.is_null() {
+                None
+            } else {
+                Some(Group(group))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Add an object to the group.
+    #[inline]
+    pub fn add(&mut self, obj: &impl ObjT) {
+        unsafe {
+            lv_group_add_obj(self.0, obj.as_lv_obj());
+        }
+    }
+
+    /// Remove an object from its group.
+    #[inline]
+    pub fn remove(&mut self, obj: &impl ObjT) {
+        unsafe {
+            lv_group_remove_obj(obj.as_lv_obj());
+        }
+    }
+
+    /// Focus an object of the group.
+    #[inline]
+    pub fn focus(&mut self, obj: &impl ObjT) {
+        unsafe {
+            lv_group_focus_obj(obj.as_lv_obj());
+        }
+    }
+
+    /// Focus the next object of the group.
+    #[inline]
+    pub fn focus_next(&mut self) {
+        unsafe {
+            lv_group_focus_next(self.0);
+        }
+    }
+
+    /// Focus the previous object of the group.
+    #[inline]
+    pub fn focus_prev(&mut self) {
+        unsafe {
+            lv_group_focus_prev(self.0);
+        }
+    }
+
+    /// Do not let the focus change.
+    #[inline]
+    pub fn focus_freeze(&mut self, enabled: bool) {
+        unsafe {
+            lv_group_focus_freeze(self.0, enabled);
+        }
+    }
+
+    /// Send a control key to the focused object.
+    #[inline]
+    pub fn send_key(&mut self, key: GroupKey) {
+        self.send_data(key as u32);
+    }
+
+    /// Send a character or a control key to the focused object.
+    #[inline]
+    pub fn send_data(&mut self, c: u32) {
+        unsafe {
+            lv_group_send_data(self.0, c);
+        }
+    }
+
+    /// Set a function to modify the style of the focused object. It returns
+    /// the builder it is given, with the changes.
+    #[inline]
+    pub fn set_style_mod<F>(&mut self, style_mod: F)
+    where
+        F: Fn(StyleBuilder<'_>) -> StyleBuilder<'_> + Copy + 'static,
+    {
+        callback::assert_zero_sized(&style_mod);
+        unsafe {
+            lv_group_set_style_mod_cb(self.0, Some(style_mod_trampoline::<F>));
+        }
+    }
+
+    /// Set a function to modify the style of the focused object in edit
+    /// mode, like `set_style_mod`.
+    #[inline]
+    pub fn set_style_mod_edit<F>(&mut self, style_mod: F)
+    where
+        F: Fn(StyleBuilder<'_>) -> StyleBuilder<'_> + Copy + 'static,
+    {
+        callback::assert_zero_sized(&style_mod);
+        unsafe {
+            lv_group_set_style_mod_edit_cb(self.0, Some(style_mod_trampoline::<F>));
+        }
+    }
+
+    /// Set a function to be called with the newly focused object when the
+    /// focus changes.
+    #[inline]
+    pub fn set_focus_cb<F>(&mut self, focus_cb: F)
+    where
+        F: Fn(ObjRef<'_>) + Copy + 'static,
+    {
+        callback::assert_zero_sized(&focus_cb);
+        unsafe {
+            lv_group_set_focus_cb(self.0, Some(focus_trampoline::<F>));
+        }
+    }
+
+    /// Switch between navigate mode and edit mode, where the keys are sent
+    /// to the focused object.
+    #[inline]
+    pub fn set_editing(&mut self, editing: bool) {
+        unsafe {
+            lv_group_set_editing(self.0, editing);
+        }
+    }
+
+    /// Focus objects of the group when they are clicked.
+    #[inline]
+    pub fn set_click_focus(&mut self, enabled: bool) {
+        unsafe {
+            lv_group_set_click_focus(self.0, enabled);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the focused object.
+    #[inline]
+    pub fn get_focused(&self) -> Option<ObjRef<'_>> {
+        unsafe {
+            let obj = lv_group_get_focused(self.0);
+            if obj.is_null() {
+                None
+            } else {
+                Some(ObjRef::from_raw(obj))
+            }
+        }
+    }
+
+    /// Get whether the group is in edit mode.
+    #[inline]
+    pub fn get_editing(&self) -> bool {
+        unsafe { lv_group_get_editing(self.0) }
+    }
+
+    /// Get whether objects are focused when they are clicked.
+    #[inline]
+    pub fn get_click_focus(&self) -> bool {
+        unsafe { lv_group_get_click_focus(self.0) }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn as_lv_group(&self) -> *mut lv_group_t {
+        self.0
+    }
+}
+
+impl Drop for Group {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            // lvgl does not detach the group from the input devices itself.
+            let mut indev = lv_indev_next(ptr::null_mut());
+            while !indev.is_null() {
+                let device_type = u32::from((*indev).driver.type_);
+                let keys =
+                    device_type == LV_INDEV_TYPE_KEYPAD || device_type == LV_INDEV_TYPE_ENCODER;
+                if keys && (*indev).__bindgen_anon_1.group == self.0 {
+                    lv_indev_set_group(indev, ptr::null_mut());
+                }
+                indev = lv_indev_next(indev);
+            }
+            lv_group_del(self.0);
+        }
+    }
+}
+
+unsafe extern "C" fn style_mod_trampoline<F>(style: *mut lv_style_t)
+where
+    F: Fn(StyleBuilder<'_>) -> StyleBuilder<'_> + Copy,
+{
+    let style_mod: F = callback::recreate();
+    style_mod(StyleBuilder::unreported(style)).apply();
+}
+
+unsafe extern "C" fn focus_trampoline<F>(group: *mut lv_group_t)
+where
+    F: Fn(ObjRef<'_>) + Copy,
+{
+    let focus_cb: F = callback::recreate();
+    let obj = lv_group_get_focused(group);
+    if !obj.is_null() {
+        focus_cb(ObjRef::from_raw(obj));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::display::Point;
+#[cfg(use_lv_group)]
+use crate::group::Group;
+use crate::obj::ObjT;
+
+use core::ffi::c_void;
+use core::mem;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputDeviceType {
+    /// Touchpad or mouse.
+    Pointer = LV_INDEV_TYPE_POINTER as lv_hal_indev_type_t,
+    /// Keyboard or keypad.
+    Keypad = LV_INDEV_TYPE_KEYPAD as lv_hal_indev_type_t,
+    /// Hardware buttons assigned to points on the screen.
+    Button = LV_INDEV_TYPE_BUTTON as lv_hal_indev_type_t,
+    /// Rotary encoder with a push button.
+    Encoder = LV_INDEV_TYPE_ENCODER as lv_hal_indev_type_t,
+}
+
+/// The state of an input device, matching its type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputData {
+    Pointer { point: Point, pressed: bool },
+    Keypad { key: u32, pressed: bool },
+    Button { id: u32, pressed: bool },
+    Encoder { diff: i16, pressed: bool },
+}
+
+/// An input device polled by lvgl every `LV_INDEV_READ_PERIOD` milliseconds.
+pub trait InputDevice {
+    /// Get the type of the device. It must not change after registration.
+    fn device_type(&self) -> InputDeviceType;
+
+    /// Read the current state of the device.
+    fn read(&mut self) -> InputData;
+
+    /// Check whether more data is buffered after a `read`, so lvgl reads
+    /// again immediately.
+    #[inline]
+    fn buffered(&self) -> bool {
+        false
+    }
+}
+
+/// A registered input device.
+#[derive(Clone, Copy)]
+pub struct Indev(*mut lv_indev_t);
+
+impl Indev {
+    /// Register an input device. Returns `None` if there is not enough memory.
+    #[inline]
+    pub fn register<D: InputDevice>(device: &'static mut D) -> Option<Self> {
+        unsafe {
+            // lvgl keeps a copy of the driver.
+            let mut driver: lv_indev_drv_t = mem::zeroed();
+            lv_indev_drv_init(&mut driver);
+            driver.type_ = device.device_type() as lv_hal_indev_type_t;
+            driver.read = Some(read_trampoline::<D>);
+            driver.user_data = device as *mut D as *mut c_void;
+            let indev = lv_indev_drv_register(&mut driver);
+            if indev.is_null() {
+                None
+            } else {
+                Some(Indev(indev))
+            }
+        }
+    }
+
+    /// Get the input device which is currently processed, if any.
+    #[inline]
+    pub fn active() -> Option<Self> {
+        unsafe {
+            let indev = lv_indev_get_act();
+            if indev.is_null() {
+                None
+            } else {
+                Some(Indev(indev))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set an object as the cursor of a pointer device.
+    #[inline]
+    pub fn set_cursor(&mut self, cursor: &impl ObjT) {
+        unsafe {
+            lv_indev_set_cursor(self.0, cursor.as_lv_obj());
+        }
+    }
+
+    /// Send the keys of a keypad or encoder device to a group, until the group
+    /// is dropped.
+    #[cfg(use_lv_group)]
+    #[inline]
+    pub fn set_group(&mut self, group: &Group) {
+        unsafe {
+            lv_indev_set_group(self.0, group.as_lv_group());
+        }
+    }
+
+    /// Set the points on the screen which are pressed by the buttons of a
+    /// button device, indexed by the button id.
+    #[inline]
+    pub fn set_button_points(&mut self, points: &'static [Point]) {
+        unsafe {
+            lv_indev_set_button_points(self.0, points.as_ptr() as *mut lv_point_t);
+        }
+    }
+
+    /// Reset the processing of the device, e.g. after a new screen has been
+    /// loaded.
+    #[inline]
+    pub fn reset(&mut self) {
+        unsafe {
+            lv_indev_reset(self.0);
+        }
+    }
+
+    /// Do nothing until the next release.
+    #[inline]
+    pub fn wait_release(&mut self) {
+        unsafe {
+            lv_indev_wait_release(self.0);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the last point of a pointer or button device.
+    #[inline]
+    pub fn get_point(&self) -> Point {
+        unsafe {
+            let mut point = lv_point_t { x: 0, y: 0 };
+            lv_indev_get_point(self.0, &mut point);
+            point.into()
+        }
+    }
+
+    /// Get the last pressed key of a keypad device.
+    #[inline]
+    pub fn get_key(&self) -> u32 {
+        unsafe { lv_indev_get_key(self.0) }
+    }
+
+    /// Check whether an object is being dragged with the device.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        unsafe { lv_indev_is_dragging(self.0) }
+    }
+}
+
+unsafe extern "C" fn read_trampoline<D: InputDevice>(data: *mut lv_indev_data_t) -> bool {
+    // lvgl passes the user data of the driver with every read.
+    let device = &mut *((*data).user_data as *mut D);
+    let pressed = match device.read() {
+        InputData::Pointer { point, pressed } => {
+            (*data).__bindgen_anon_1.point = point.into();
+            pressed
+        }
+        InputData::Keypad { key, pressed } => {
+            (*data).__bindgen_anon_1.key = key;
+            pressed
+        }
+        InputData::Button { id, pressed } => {
+            (*data).__bindgen_anon_1.btn = id;
+            pressed
+        }
+        InputData::Encoder { diff, pressed } => {
+            (*data).__bindgen_anon_1.enc_diff = diff;
+            pressed
+        }
+    };
+    (*data).state = if pressed {
+        LV_INDEV_STATE_PR as lv_indev_state_t
+    } else {
+        LV_INDEV_STATE_REL as lv_indev_state_t
+    };
+    device.buffered()
+}
@@ -0,0 +1,78 @@
+use core::cell::UnsafeCell;
+use core::mem;
+use core::ops::Deref;
+use core::sync::atomic::{self, AtomicU8, Ordering};
+
+const UNINIT: u8 = 0;
+const RUNNING: u8 = 1;
+const READY: u8 = 2;
+const POISONED: u8 = 3;
+
+/// A value built by `init` on its first use. It lets statics hold values only
+/// known at run time, such as the styles and the themes based on the built-in
+/// styles, e.g. `static TEXT: Lazy<Style> = Lazy::new(|| Style::build(|style|
+/// style.copy_from(Style::pretty()).text_color(Color::WHITE)));`.
+///
+/// A panic in `init` poisons the value, and later uses panic too. `init` must
+/// not use the value itself, which would wait for it forever.
+pub struct Lazy<T, F = fn() -> T> {
+    state: AtomicU8,
+    value: UnsafeCell<Option<T>>,
+    init: F,
+}
+
+// The value is only written by the thread which moved the state from
+// `UNINIT` to `RUNNING`, and only read once the state is `READY`.
+unsafe impl<T: Send + Sync, F: Sync> Sync for Lazy<T, F> {}
+
+impl<T, F> Lazy<T, F> {
+    #[inline]
+    pub const fn new(init: F) -> Self {
+        Lazy {
+            state: AtomicU8::new(UNINIT),
+            value: UnsafeCell::new(None),
+            init,
+        }
+    }
+}
+
+impl<T, F: Fn() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        if self
+            .state
+            .compare_exchange(UNINIT, RUNNING, Ordering::Acquire, Ordering::Acquire)
+            .is_ok()
+        {
+            let poison = Poison(&self.state);
+            let value = (self.init)();
+            unsafe {
+                *self.value.get() = Some(value);
+            }
+            mem::forget(poison);
+            self.state.store(READY, Ordering::Release);
+        }
+        loop {
+            match self.state.load(Ordering::Acquire) {
+                READY => break,
+                POISONED => panic!("Lazy value poisoned"),
+                _ => atomic::spin_loop_hint(),
+            }
+        }
+        match unsafe { &*self.value.get() } {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+/// Poison the value unless forgotten, when `init` panics.
+struct Poison<'a>(&'a AtomicU8);
+
+impl<'a> Drop for Poison<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.store(POISONED, Ordering::Release);
+    }
+}
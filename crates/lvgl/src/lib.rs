@@ -1,17 +1,38 @@
 #![cfg_attr(target_os = "none", no_std)]
 
+#[cfg(use_lv_animation)]
+mod anim;
+mod callback;
 mod color;
+mod display;
+mod event;
 mod font;
+#[cfg(use_lv_group)]
+mod group;
+mod input;
+mod lazy;
 mod obj;
+mod screen;
 mod style;
+mod task;
 mod theme;
 mod tick;
 mod widgets;
 
+#[cfg(use_lv_animation)]
+pub use self::anim::*;
 pub use self::color::*;
+pub use self::display::*;
+pub use self::event::*;
 pub use self::font::*;
+#[cfg(use_lv_group)]
+pub use self::group::*;
+pub use self::input::*;
+pub use self::lazy::*;
 pub use self::obj::*;
+pub use self::screen::*;
 pub use self::style::*;
+pub use self::task::*;
 pub use self::theme::*;
 pub use self::tick::*;
 pub use self::widgets::*;
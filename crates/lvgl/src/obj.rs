@@ -1,5 +1,11 @@
+use crate::event::{self, Event};
 use crate::style::Style;
 
+#[cfg(use_lv_animation)]
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
 use core::ptr;
 use lvgl_sys::*;
 
@@ -31,6 +37,11 @@ pub enum Align {
 pub trait ObjT {
     unsafe fn as_lv_obj(&self) -> *mut lv_obj_t;
 
+    /// Wrap a raw object pointer of the matching type.
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self
+    where
+        Self: Sized;
+
     /// Mark the object as invalid therefore its current position will be
     /// redrawn by 'lv_refr_task'.
     #[inline]
@@ -141,12 +152,77 @@ pub trait ObjT {
         }
     }
 
+    // Hierarchy.
+
+    /// Set a new parent for the object. Its relative position will be the
+    /// same.
+    ///
+    /// # Safety
+    ///
+    /// The handles to the object and to its descendants only borrow their
+    /// former parents. The new parent must not be deleted (or cleaned) while
+    /// any of them is alive, since an `Owned` handle would delete its object
+    /// again when dropped, and the other handles would use a deleted object.
+    #[inline]
+    unsafe fn set_parent(&mut self, parent: &impl ObjT) {
+        lv_obj_set_parent(self.as_lv_obj(), parent.as_lv_obj());
+    }
+
+    /// Delete all children of the object.
+    ///
+    /// # Safety
+    ///
+    /// No `Owned` handle to a child or a descendant of the object may be
+    /// alive, since it would delete the object again when dropped. `Child`
+    /// and `ObjRef` handles to them must not be used afterwards.
+    #[inline]
+    unsafe fn clean(&mut self) {
+        lv_obj_clean(self.as_lv_obj());
+    }
+
+    // Events.
+
+    /// Set a handler to be called on the input events of the object,
+    /// replacing the previous one. The handler is dropped when the object is
+    /// deleted.
+    ///
+    /// The handler may delete the object. If it replaces or clears its own
+    /// handler, the change takes effect once it returns.
+    #[inline]
+    fn on_event<F>(&mut self, handler: F)
+    where
+        Self: Sized,
+        F: FnMut(&mut Self, Event) + 'static,
+    {
+        unsafe {
+            event::set_handler::<Self, F>(self.as_lv_obj(), handler);
+        }
+    }
+
+    /// Remove the event handler of the object.
+    #[inline]
+    fn clear_event_handler(&mut self) {
+        unsafe {
+            event::clear_handler(self.as_lv_obj());
+        }
+    }
+
+    // Animations.
+
+    /// Delete all animations of the object. Returns `true` if any has been
+    /// deleted.
+    #[cfg(use_lv_animation)]
+    #[inline]
+    fn cancel_anims(&mut self) -> bool {
+        unsafe { lv_anim_del(self.as_lv_obj() as *mut c_void, None) }
+    }
+
     // Appearance setters.
 
     /// Set a new style for an object.
     #[inline]
     fn set_style(&mut self, style: &'static Style) {
-        let style = &**style as *const _ as *mut lv_style_t;
+        let style = style.as_lv_style();
         unsafe {
             lv_obj_set_style(self.as_lv_obj(), style);
         }
@@ -160,6 +236,58 @@ pub trait ObjT {
         }
     }
 
+    // Attribute setters.
+
+    /// Hide an object. It won't be visible and clickable.
+    #[inline]
+    fn set_hidden(&mut self, hidden: bool) {
+        unsafe {
+            lv_obj_set_hidden(self.as_lv_obj(), hidden);
+        }
+    }
+
+    /// Enable or disable the clicking of an object.
+    #[inline]
+    fn set_click(&mut self, enabled: bool) {
+        unsafe {
+            lv_obj_set_click(self.as_lv_obj(), enabled);
+        }
+    }
+
+    // Hierarchy getters.
+
+    /// Get the parent of the object, or `None` if the object is a screen.
+    #[inline]
+    fn get_parent(&self) -> Option<ObjRef<'_>> {
+        unsafe {
+            let parent = lv_obj_get_parent(self.as_lv_obj());
+            if parent.is_null() {
+                None
+            } else {
+                Some(ObjRef::from_raw(parent))
+            }
+        }
+    }
+
+    /// Iterate through the children of the object, starting from the
+    /// youngest.
+    #[inline]
+    fn children(&self) -> Children<'_> {
+        Children {
+            parent: unsafe { self.as_lv_obj() },
+            child: ptr::null_mut(),
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Count the children of the object (only children directly on the
+    /// object).
+    #[inline]
+    fn count_children(&self) -> u16 {
+        unsafe { lv_obj_count_children(self.as_lv_obj()) }
+    }
+
     // Coordinate getters.
 
     /// Get the x coordinate of the object.
@@ -212,3 +340,218 @@ pub trait ObjT {
         unsafe { lv_obj_get_click(self.as_lv_obj()) }
     }
 }
+
+/// A basic object, typically used as a container for other objects.
+///
+/// The object is deleted together with all of its children when it is
+/// dropped.
+#[repr(transparent)]
+pub struct Obj(*mut lv_obj_t);
+
+impl Obj {
+    /// Create a new basic object on `parent`, deleted when the returned handle
+    /// is dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new basic object as a child of `parent`, optionally copying
+    /// another object. The object is deleted together with its parent.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Obj>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let obj = lv_obj_create(parent.as_lv_obj(), copy);
+            if obj.is_null() {
+                None
+            } else {
+                Some(Child::new(Obj(obj)))
+            }
+        }
+    }
+}
+
+impl ObjT for Obj {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Obj(obj)
+    }
+}
+
+impl Drop for Obj {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+/// A non-owning handle to an object borrowed from lvgl, like the parent or a
+/// child of another object.
+#[derive(Clone, Copy)]
+pub struct ObjRef<'a> {
+    obj: *mut lv_obj_t,
+    _marker: PhantomData<&'a lv_obj_t>,
+}
+
+impl<'a> ObjRef<'a> {
+    /// Wrap a raw object pointer without taking ownership.
+    #[inline]
+    pub unsafe fn from_raw(obj: *mut lv_obj_t) -> Self {
+        ObjRef {
+            obj,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> ObjT for ObjRef<'a> {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.obj
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        ObjRef::from_raw(obj)
+    }
+}
+
+/// Iterator over the children of an object, created by `ObjT::children`.
+pub struct Children<'a> {
+    parent: *mut lv_obj_t,
+    child: *mut lv_obj_t,
+    done: bool,
+    _marker: PhantomData<&'a lv_obj_t>,
+}
+
+impl<'a> Iterator for Children<'a> {
+    type Item = ObjRef<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.child = unsafe { lv_obj_get_child(self.parent, self.child) };
+        if self.child.is_null() {
+            self.done = true;
+            None
+        } else {
+            Some(unsafe { ObjRef::from_raw(self.child) })
+        }
+    }
+}
+
+/// A non-owning handle to an object which is deleted by its parent.
+///
+/// The `create` constructors return their object wrapped in `Child`, which
+/// leaves the deletion to lvgl, together with the parent. The handle borrows
+/// the parent, so the parent cannot be dropped while the handle is alive.
+pub struct Child<'a, T: ObjT> {
+    obj: ManuallyDrop<T>,
+    _parent: PhantomData<&'a lv_obj_t>,
+}
+
+impl<'a, T: ObjT> Child<'a, T> {
+    /// Wrap an object created as a descendant of a parent borrowed for `'a`.
+    ///
+    /// # Safety
+    ///
+    /// lvgl must not delete the object during `'a`, other than through the
+    /// returned handle.
+    #[inline]
+    pub unsafe fn new(obj: T) -> Self {
+        Child {
+            obj: ManuallyDrop::new(obj),
+            _parent: PhantomData,
+        }
+    }
+
+    /// Take the ownership of the object, so it is deleted when the returned
+    /// handle is dropped. It still borrows the parent.
+    #[inline]
+    pub fn into_owned(self) -> Owned<'a, T> {
+        Owned {
+            obj: ManuallyDrop::into_inner(self.obj),
+            _parent: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ObjT> ObjT for Child<'a, T> {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.obj.as_lv_obj()
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Child::new(T::from_lv_obj(obj))
+    }
+}
+
+impl<'a, T: ObjT> Deref for Child<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.obj
+    }
+}
+
+impl<'a, T: ObjT> DerefMut for Child<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.obj
+    }
+}
+
+/// An owning handle to an object which has a parent, deleting the object
+/// together with its children when dropped.
+///
+/// The `new` constructors return their object wrapped in `Owned`. Like
+/// `Child`, the handle borrows the parent, so lvgl cannot delete the object
+/// with its parent before the handle is dropped.
+pub struct Owned<'a, T: ObjT> {
+    obj: T,
+    _parent: PhantomData<&'a lv_obj_t>,
+}
+
+impl<'a, T: ObjT> ObjT for Owned<'a, T> {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.obj.as_lv_obj()
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Owned {
+            obj: T::from_lv_obj(obj),
+            _parent: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ObjT> Deref for Owned<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.obj
+    }
+}
+
+impl<'a, T: ObjT> DerefMut for Owned<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.obj
+    }
+}
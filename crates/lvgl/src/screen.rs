@@ -0,0 +1,133 @@
+use crate::obj::{ObjRef, ObjT};
+
+use core::mem;
+use core::ptr;
+use lvgl_sys::*;
+
+/// Animation played on the children of a screen when it is loaded.
+#[cfg(use_lv_animation)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ScreenTransition {
+    FloatFromTop = LV_ANIM_FLOAT_TOP as lv_anim_builtin_t,
+    FloatFromLeft = LV_ANIM_FLOAT_LEFT as lv_anim_builtin_t,
+    FloatFromBottom = LV_ANIM_FLOAT_BOTTOM as lv_anim_builtin_t,
+    FloatFromRight = LV_ANIM_FLOAT_RIGHT as lv_anim_builtin_t,
+    GrowHorizontal = LV_ANIM_GROW_H as lv_anim_builtin_t,
+    GrowVertical = LV_ANIM_GROW_V as lv_anim_builtin_t,
+}
+
+/// A screen, i.e. a top level object without a parent. Only the active
+/// screen is displayed.
+///
+/// The screen is deleted together with all of its children when it is
+/// dropped. The objects created on it borrow the screen, so it cannot be
+/// dropped before their handles. Another screen has to be loaded before
+/// dropping the active one, otherwise the active screen is leaked to lvgl
+/// instead. `delete` reports this case.
+#[repr(transparent)]
+pub struct Screen(*mut lv_obj_t);
+
+impl Screen {
+    /// Create a new screen.
+    #[inline]
+    pub fn new() -> Option<Self> {
+        unsafe {
+            let screen = lv_obj_create(ptr::null_mut(), ptr::null());
+            if screen.is_null() {
+                None
+            } else {
+                Some(Screen(screen))
+            }
+        }
+    }
+
+    /// Leak the screen, so it is never deleted and objects can be created on
+    /// it for the rest of the program.
+    #[inline]
+    pub fn leak(self) -> &'static mut Screen {
+        unsafe {
+            let screen = lv_mem_alloc(mem::size_of::<Screen>() as u32) as *mut Screen;
+            assert!(!screen.is_null(), "Out of lvgl memory");
+            ptr::write(screen, Screen(self.0));
+            mem::forget(self);
+            &mut *screen
+        }
+    }
+
+    /// Delete the screen together with all of its children. The active screen
+    /// is not deleted, since lvgl would be left without one, and is given
+    /// back as the error.
+    #[inline]
+    pub fn delete(self) -> Result<(), Self> {
+        if self.is_active() {
+            Err(self)
+        } else {
+            drop(self);
+            Ok(())
+        }
+    }
+
+    /// Get the active screen.
+    ///
+    /// # Safety
+    ///
+    /// The returned handle must not be used after the screen has been
+    /// deleted, e.g. by dropping its `Screen` once another one is loaded.
+    #[inline]
+    pub unsafe fn active<'a>() -> ObjRef<'a> {
+        ObjRef::from_raw(lv_scr_act())
+    }
+
+    /// Load the screen, making it the active one.
+    #[inline]
+    pub fn load(&self) {
+        unsafe {
+            lv_scr_load(self.0);
+        }
+    }
+
+    /// Load the screen and animate its children into place in `time`
+    /// milliseconds.
+    #[cfg(use_lv_animation)]
+    #[inline]
+    pub fn load_with_transition(&mut self, transition: ScreenTransition, time: u16) {
+        self.load();
+        let anim_type = transition as lv_anim_builtin_t | LV_ANIM_IN as lv_anim_builtin_t;
+        for child in self.children() {
+            unsafe {
+                lv_obj_animate(child.as_lv_obj(), anim_type, time, 0, None);
+            }
+        }
+    }
+
+    /// Check whether the screen is the active one.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        unsafe { lv_scr_act() == self.0 }
+    }
+}
+
+impl ObjT for Screen {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Screen(obj)
+    }
+}
+
+impl Drop for Screen {
+    #[inline]
+    fn drop(&mut self) {
+        // Deleting the active screen would leave lvgl without one.
+        if !self.is_active() {
+            unsafe {
+                lv_obj_del(self.0);
+            }
+        }
+    }
+}
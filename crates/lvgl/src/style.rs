@@ -1,30 +1,46 @@
+use crate::color::Color;
+use crate::font::Font;
+
+use core::cell::UnsafeCell;
 use core::mem;
-use core::ops::{Deref, DerefMut};
 use core::ptr;
 use lvgl_sys::*;
 
 const STYLE_SIZE: usize = mem::size_of::<lv_style_t>();
 
 #[repr(C)]
-pub union Style {
+union StyleData {
     style: lv_style_t,
     empty: [u8; STYLE_SIZE],
 }
 
+/// An lvgl style. Styles are referenced by the objects using them, so they
+/// are meant to be `static`, built with `build` in a `Lazy` static, and
+/// changed afterwards with `modify_live`.
+#[repr(transparent)]
+pub struct Style(UnsafeCell<StyleData>);
+
+// A shared style is only written through the unsafe `modify_live`, whose
+// callers guarantee there is no concurrent access.
+unsafe impl Sync for Style {}
+
+// The only pointer of a style is its font, which is static.
+unsafe impl Send for Style {}
+
 impl Style {
     #[inline]
-    pub fn plain() -> &'static mut Style {
-        unsafe { &mut *(&mut lv_style_plain as *mut _ as *mut Style) }
+    pub fn plain() -> &'static Style {
+        unsafe { Style::from_lv_style(&mut lv_style_plain) }
     }
 
     #[inline]
-    pub fn pretty() -> &'static mut Style {
-        unsafe { &mut *(&mut lv_style_pretty as *mut _ as *mut Style) }
+    pub fn pretty() -> &'static Style {
+        unsafe { Style::from_lv_style(&mut lv_style_pretty) }
     }
 
     #[inline]
-    pub fn screen() -> &'static mut Style {
-        unsafe { &mut *(&mut lv_style_scr as *mut _ as *mut Style) }
+    pub fn screen() -> &'static Style {
+        unsafe { Style::from_lv_style(&mut lv_style_scr) }
     }
 
     /// Notify all objects that styles have changed.
@@ -37,16 +53,45 @@ impl Style {
 
     #[inline]
     pub const fn new_empty() -> Self {
-        Style {
+        Style(UnsafeCell::new(StyleData {
             empty: [0u8; STYLE_SIZE],
+        }))
+    }
+
+    /// Build a new style from an empty one.
+    #[inline]
+    pub fn build<F>(build: F) -> Self
+    where
+        F: FnOnce(StyleBuilder<'_>) -> StyleBuilder<'_>,
+    {
+        let mut style = Style::new_empty();
+        build(style.modify()).apply();
+        style
+    }
+
+    /// Start modifying a style no object uses yet.
+    #[inline]
+    pub fn modify(&mut self) -> StyleBuilder<'_> {
+        StyleBuilder {
+            style: unsafe { *self.as_lv_style() },
+            target: self,
+            report: false,
         }
     }
 
-    /// Initialize from a copy of another style.
+    /// Start modifying a style which may be used by objects. They are
+    /// notified when the builder is applied.
+    ///
+    /// # Safety
+    ///
+    /// The style must not be accessed concurrently: it may only be modified
+    /// from the thread running lvgl.
     #[inline]
-    pub fn copy_from(&mut self, other: &Style) {
-        unsafe {
-            lv_style_copy(&mut self.style, &other.style);
+    pub unsafe fn modify_live(&self) -> StyleBuilder<'_> {
+        StyleBuilder {
+            style: *self.as_lv_style(),
+            target: self,
+            report: true,
         }
     }
 
@@ -54,25 +99,315 @@ impl Style {
     #[inline]
     pub fn report_mod(&self) {
         unsafe {
-            let style = &self.style as *const _ as *mut lv_style_t;
-            lv_obj_report_style_mod(style);
+            lv_obj_report_style_mod(self.as_lv_style());
         }
     }
 
+    #[inline]
+    pub(crate) unsafe fn from_lv_style<'a>(style: *mut lv_style_t) -> &'a Style {
+        &*(style as *const Style)
+    }
+
+    #[inline]
+    pub(crate) fn as_lv_style(&self) -> *mut lv_style_t {
+        self.0.get() as *mut lv_style_t
+    }
+}
+
+/// Parts of the border to draw.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BorderPart(lv_border_part_t);
+
+impl BorderPart {
+    pub const NONE: BorderPart = BorderPart(LV_BORDER_NONE as lv_border_part_t);
+    pub const BOTTOM: BorderPart = BorderPart(LV_BORDER_BOTTOM as lv_border_part_t);
+    pub const TOP: BorderPart = BorderPart(LV_BORDER_TOP as lv_border_part_t);
+    pub const LEFT: BorderPart = BorderPart(LV_BORDER_LEFT as lv_border_part_t);
+    pub const RIGHT: BorderPart = BorderPart(LV_BORDER_RIGHT as lv_border_part_t);
+    pub const FULL: BorderPart = BorderPart(LV_BORDER_FULL as lv_border_part_t);
 }
 
-impl Deref for Style {
-    type Target = lv_style_t;
+impl core::ops::BitOr for BorderPart {
+    type Output = BorderPart;
 
     #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { &self.style }
+    fn bitor(self, other: BorderPart) -> BorderPart {
+        BorderPart(self.0 | other.0)
     }
 }
 
-impl DerefMut for Style {
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ShadowType {
+    /// Only draw the shadow below the body.
+    Bottom = LV_SHADOW_BOTTOM as lv_shadow_type_t,
+    /// Draw the shadow all around the body.
+    Full = LV_SHADOW_FULL as lv_shadow_type_t,
+}
+
+/// Builder of the changes of a style, returned by `Style::modify`. The changes
+/// are only written to the style by `apply`, so they are discarded if the
+/// builder is dropped before, e.g. by a panic in a setter.
+#[must_use = "the changes are only written by `apply`"]
+pub struct StyleBuilder<'a> {
+    style: lv_style_t,
+    target: &'a Style,
+    report: bool,
+}
+
+impl<'a> StyleBuilder<'a> {
+    /// Modify a style lvgl is about to use, without reporting the changes.
+    #[inline]
+    pub(crate) unsafe fn unreported(style: *mut lv_style_t) -> Self {
+        StyleBuilder {
+            style: *style,
+            target: Style::from_lv_style(style),
+            report: false,
+        }
+    }
+
+    /// Write the changes to the style, and notify the objects using it when
+    /// it was modified with `modify_live`.
+    #[inline]
+    pub fn apply(self) {
+        unsafe {
+            *self.target.as_lv_style() = self.style;
+        }
+        if self.report {
+            self.target.report_mod();
+        }
+    }
+
+    /// Start from a copy of another style.
+    #[inline]
+    pub fn copy_from(mut self, other: &Style) -> Self {
+        unsafe {
+            lv_style_copy(&mut self.style, other.as_lv_style());
+        }
+        self
+    }
+
+    /// Make the style transparent to the styles of the children, which
+    /// inherit the style of the parent instead.
+    #[inline]
+    pub fn glass(mut self, glass: bool) -> Self {
+        self.style.set_glass(glass as u8);
+        self
+    }
+
+    // Body.
+
+    /// Set the main color of the body, at the top of the gradient.
+    #[inline]
+    pub fn body_main_color(mut self, color: Color) -> Self {
+        self.style.body.main_color = color.into();
+        self
+    }
+
+    /// Set the gradient color of the body, at the bottom of the gradient.
+    #[inline]
+    pub fn body_grad_color(mut self, color: Color) -> Self {
+        self.style.body.grad_color = color.into();
+        self
+    }
+
+    /// Set both colors of the body, for a body without a gradient.
+    #[inline]
+    pub fn body_color(self, color: Color) -> Self {
+        self.body_main_color(color).body_grad_color(color)
+    }
+
+    /// Set the radius of the corners of the body.
+    #[inline]
+    pub fn body_radius(mut self, radius: u16) -> Self {
+        assert!(fits_coord(radius.into()), "Radius out of range");
+        self.style.body.radius = radius as lv_coord_t;
+        self
+    }
+
+    /// Round the ends of the body into a circle.
+    #[inline]
+    pub fn body_radius_circle(mut self) -> Self {
+        self.style.body.radius = LV_RADIUS_CIRCLE as lv_coord_t;
+        self
+    }
+
+    /// Set the opacity of the body.
+    #[inline]
+    pub fn body_opa(mut self, opa: lv_opa_t) -> Self {
+        self.style.body.opa = opa;
+        self
+    }
+
+    /// Only draw the border and the shadow of the body.
+    #[inline]
+    pub fn body_empty(mut self, empty: bool) -> Self {
+        self.style.body.set_empty(empty as u8);
+        self
+    }
+
+    /// Set the color of the border.
+    #[inline]
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.style.body.border.color = color.into();
+        self
+    }
+
+    /// Set the width of the border.
+    #[inline]
+    pub fn border_width(mut self, width: u16) -> Self {
+        assert!(fits_coord(width.into()), "Border width out of range");
+        self.style.body.border.width = width as lv_coord_t;
+        self
+    }
+
+    /// Set the parts of the border to draw.
+    #[inline]
+    pub fn border_part(mut self, part: BorderPart) -> Self {
+        self.style.body.border.part = part.0;
+        self
+    }
+
+    /// Set the opacity of the border.
+    #[inline]
+    pub fn border_opa(mut self, opa: lv_opa_t) -> Self {
+        self.style.body.border.opa = opa;
+        self
+    }
+
+    /// Set the color of the shadow.
+    #[inline]
+    pub fn shadow_color(mut self, color: Color) -> Self {
+        self.style.body.shadow.color = color.into();
+        self
+    }
+
+    /// Set the width of the shadow.
+    #[inline]
+    pub fn shadow_width(mut self, width: u16) -> Self {
+        assert!(fits_coord(width.into()), "Shadow width out of range");
+        self.style.body.shadow.width = width as lv_coord_t;
+        self
+    }
+
+    /// Set the type of the shadow.
+    #[inline]
+    pub fn shadow_type(mut self, shadow_type: ShadowType) -> Self {
+        self.style.body.shadow.type_ = shadow_type as lv_shadow_type_t;
+        self
+    }
+
+    /// Set the horizontal and vertical padding of the body.
+    #[inline]
+    pub fn padding(mut self, hor: u16, ver: u16) -> Self {
+        assert!(fits_coord(hor.into()), "Padding out of range");
+        assert!(fits_coord(ver.into()), "Padding out of range");
+        self.style.body.padding.hor = hor as lv_coord_t;
+        self.style.body.padding.ver = ver as lv_coord_t;
+        self
+    }
+
+    /// Set the padding between the children of the body.
+    #[inline]
+    pub fn padding_inner(mut self, inner: u16) -> Self {
+        assert!(fits_coord(inner.into()), "Padding out of range");
+        self.style.body.padding.inner = inner as lv_coord_t;
+        self
+    }
+
+    // Text.
+
+    /// Set the color of the text.
+    #[inline]
+    pub fn text_color(mut self, color: Color) -> Self {
+        self.style.text.color = color.into();
+        self
+    }
+
+    /// Set the font of the text.
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut self.style }
+    pub fn text_font(mut self, font: Font) -> Self {
+        let font: *const lv_font_t = font.into();
+        assert!(!font.is_null(), "Null font");
+        self.style.text.font = font;
+        self
     }
+
+    /// Set the space between the letters of the text.
+    #[inline]
+    pub fn text_letter_space(mut self, space: lv_coord_t) -> Self {
+        assert!(fits_coord(space.into()), "Letter space out of range");
+        self.style.text.letter_space = space;
+        self
+    }
+
+    /// Set the space between the lines of the text.
+    #[inline]
+    pub fn text_line_space(mut self, space: lv_coord_t) -> Self {
+        assert!(fits_coord(space.into()), "Line space out of range");
+        self.style.text.line_space = space;
+        self
+    }
+
+    /// Set the opacity of the text.
+    #[inline]
+    pub fn text_opa(mut self, opa: lv_opa_t) -> Self {
+        self.style.text.opa = opa;
+        self
+    }
+
+    // Image.
+
+    /// Mix `color` into images, from 0 (original image) to 255 (only the
+    /// color).
+    #[inline]
+    pub fn image_recolor(mut self, color: Color, intense: lv_opa_t) -> Self {
+        self.style.image.color = color.into();
+        self.style.image.intense = intense;
+        self
+    }
+
+    /// Set the opacity of images.
+    #[inline]
+    pub fn image_opa(mut self, opa: lv_opa_t) -> Self {
+        self.style.image.opa = opa;
+        self
+    }
+
+    // Line.
+
+    /// Set the color of lines.
+    #[inline]
+    pub fn line_color(mut self, color: Color) -> Self {
+        self.style.line.color = color.into();
+        self
+    }
+
+    /// Set the width of lines.
+    #[inline]
+    pub fn line_width(mut self, width: u16) -> Self {
+        assert!(fits_coord(width.into()), "Line width out of range");
+        self.style.line.width = width as lv_coord_t;
+        self
+    }
+
+    /// Set the opacity of lines.
+    #[inline]
+    pub fn line_opa(mut self, opa: lv_opa_t) -> Self {
+        self.style.line.opa = opa;
+        self
+    }
+
+    /// Round the ends of lines.
+    #[inline]
+    pub fn line_rounded(mut self, rounded: bool) -> Self {
+        self.style.line.set_rounded(rounded as u8);
+        self
+    }
+}
+
+/// Check a size or a distance fits the coordinates of lvgl.
+#[inline]
+fn fits_coord(value: i32) -> bool {
+    value.abs() as u32 <= LV_COORD_MAX
 }
@@ -0,0 +1,234 @@
+use core::ffi::c_void;
+use core::mem;
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskPrio {
+    Lowest = lv_task_prio_t_LV_TASK_PRIO_LOWEST as u8,
+    Low = lv_task_prio_t_LV_TASK_PRIO_LOW as u8,
+    Mid = lv_task_prio_t_LV_TASK_PRIO_MID as u8,
+    High = lv_task_prio_t_LV_TASK_PRIO_HIGH as u8,
+    Highest = lv_task_prio_t_LV_TASK_PRIO_HIGHEST as u8,
+}
+
+/// A periodic task run by `lv_task_handler` with a Rust closure.
+///
+/// The task is deleted when it is dropped, which may also be done by its own
+/// closure.
+pub struct Task {
+    task: *mut lv_task_t,
+    record: *mut Header,
+    release: unsafe fn(*mut Header),
+    prio: TaskPrio,
+    paused: bool,
+}
+
+impl Task {
+    /// Create a new task calling `f` every `period` milliseconds.
+    #[inline]
+    pub fn new<F>(period: u32, prio: TaskPrio, f: F) -> Option<Self>
+    where
+        F: FnMut() + 'static,
+    {
+        unsafe {
+            let record = alloc(Record {
+                header: Header {
+                    running: false,
+                    released: false,
+                },
+                f,
+            })? as *mut Header;
+            let task = lv_task_create(
+                Some(task_trampoline::<F>),
+                period,
+                prio as lv_task_prio_t,
+                record as *mut c_void,
+            );
+            if task.is_null() {
+                release::<F>(record);
+                None
+            } else {
+                Some(Task {
+                    task,
+                    record,
+                    release: release::<F>,
+                    prio,
+                    paused: false,
+                })
+            }
+        }
+    }
+
+    /// Create a one-shot task calling `f` once after `delay` milliseconds.
+    /// Returns `false` if there is not enough memory.
+    #[inline]
+    pub fn once<F>(delay: u32, prio: TaskPrio, f: F) -> bool
+    where
+        F: FnOnce() + 'static,
+    {
+        unsafe {
+            let record = match alloc(f) {
+                Some(record) => record,
+                None => return false,
+            };
+            let task = lv_task_create(
+                Some(once_trampoline::<F>),
+                delay,
+                prio as lv_task_prio_t,
+                record as *mut c_void,
+            );
+            if task.is_null() {
+                ptr::drop_in_place(record);
+                lv_mem_free(record as *mut c_void);
+                return false;
+            }
+            // lvgl deletes the task after its first run.
+            lv_task_once(task);
+            true
+        }
+    }
+
+    // Setter methods.
+
+    /// Set new period for the task.
+    #[inline]
+    pub fn set_period(&mut self, period: u32) {
+        unsafe {
+            lv_task_set_period(self.task, period);
+        }
+    }
+
+    /// Set new priority for the task. A paused task keeps waiting for
+    /// `resume`.
+    #[inline]
+    pub fn set_prio(&mut self, prio: TaskPrio) {
+        self.prio = prio;
+        if !self.paused {
+            unsafe {
+                lv_task_set_prio(self.task, prio as lv_task_prio_t);
+            }
+        }
+    }
+
+    /// Stop running the task until `resume` is called.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+        unsafe {
+            lv_task_set_prio(self.task, lv_task_prio_t_LV_TASK_PRIO_OFF);
+        }
+    }
+
+    /// Run the task again with its priority.
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+        unsafe {
+            lv_task_set_prio(self.task, self.prio as lv_task_prio_t);
+        }
+    }
+
+    /// Make the task ready to run on the next call of `lv_task_handler`.
+    #[inline]
+    pub fn ready(&mut self) {
+        unsafe {
+            lv_task_ready(self.task);
+        }
+    }
+
+    /// Reset the task, so it runs again after a full period.
+    #[inline]
+    pub fn reset(&mut self) {
+        unsafe {
+            lv_task_reset(self.task);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the priority of the task.
+    #[inline]
+    pub fn get_prio(&self) -> TaskPrio {
+        self.prio
+    }
+
+    /// Check whether the task is paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+impl Drop for Task {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_task_del(self.task);
+            // A task dropped by its own closure is released once the closure
+            // returns.
+            if (*self.record).running {
+                (*self.record).released = true;
+            } else {
+                (self.release)(self.record);
+            }
+        }
+    }
+}
+
+/// Type-erased header of the closure record of a periodic task.
+#[repr(C)]
+struct Header {
+    /// Set while the closure is running.
+    running: bool,
+    /// Set if the task has been dropped while the closure is running.
+    released: bool,
+}
+
+#[repr(C)]
+struct Record<F> {
+    header: Header,
+    f: F,
+}
+
+/// Move `value` into memory allocated by lvgl.
+unsafe fn alloc<T>(value: T) -> Option<*mut T> {
+    // lvgl allocates with pointer alignment.
+    assert!(mem::align_of::<T>() <= mem::align_of::<usize>());
+
+    let record = lv_mem_alloc(mem::size_of::<T>() as u32) as *mut T;
+    if record.is_null() {
+        None
+    } else {
+        ptr::write(record, value);
+        Some(record)
+    }
+}
+
+unsafe fn release<F>(header: *mut Header) {
+    ptr::drop_in_place(header as *mut Record<F>);
+    lv_mem_free(header as *mut c_void);
+}
+
+unsafe extern "C" fn task_trampoline<F>(record: *mut c_void)
+where
+    F: FnMut(),
+{
+    let header = record as *mut Header;
+    (*header).running = true;
+    ((*(record as *mut Record<F>)).f)();
+    (*header).running = false;
+    if (*header).released {
+        release::<F>(header);
+    }
+}
+
+unsafe extern "C" fn once_trampoline<F>(record: *mut c_void)
+where
+    F: FnOnce(),
+{
+    let f = ptr::read(record as *mut F);
+    lv_mem_free(record);
+    f();
+}
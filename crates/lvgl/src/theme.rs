@@ -1,19 +1,263 @@
+use crate::font::Font;
+use crate::style::Style;
+
 use core::mem;
-use core::ops::{Deref, DerefMut};
 use lvgl_sys::*;
 
-const THEME_SIZE: usize = mem::size_of::<lv_theme_t>();
-
+/// A set of styles given to the new objects, one for each type of object and
+/// state. The objects keep the styles of their parts, e.g. the buttons of a
+/// button matrix or a list and the selected option of a drop down list or a
+/// roller, and draw with them, so every part is set. New labels, images and
+/// lines still inherit the style of their parent, their parts are there to be
+/// set explicitly.
+///
+/// A custom theme is built from `new_from_builtins` and the styles to set,
+/// e.g. `Theme { bg: Some(&BG_STYLE), ..Theme::new_from_builtins() }`. The
+/// built-in styles are only known at run time, so a static theme is built in
+/// a `Lazy`. The fields follow the objects enabled in lv_conf.h.
+///
+/// The built-in themes are enabled by the `theme-<name>` features. Without the
+/// `theme-live-update` feature, switching the current theme only changes the
+/// objects created afterwards. With it, lvgl copies the styles of the new
+/// theme into the ones used by the objects and refreshes them, so e.g. a day
+/// and a night theme can be switched at run time with `set_current`, once
+/// the first theme has been set before creating the objects.
 #[repr(C)]
-pub union Theme {
-    lv_theme: lv_theme_t,
-    empty: [u8; THEME_SIZE],
+pub struct Theme {
+    /// Background of the screens.
+    pub bg: Option<&'static Style>,
+    /// General panels.
+    pub panel: Option<&'static Style>,
+    /// Containers.
+    #[cfg(use_lv_cont)]
+    pub cont: Option<&'static Style>,
+    /// Buttons.
+    #[cfg(use_lv_btn)]
+    pub btn: ThemeButton,
+    /// Image buttons.
+    #[cfg(use_lv_imgbtn)]
+    pub imgbtn: ThemeButton,
+    /// Labels.
+    #[cfg(use_lv_label)]
+    pub label: ThemeLabel,
+    /// Images.
+    #[cfg(use_lv_img)]
+    pub img: ThemeImage,
+    /// Lines.
+    #[cfg(use_lv_line)]
+    pub line: ThemeLine,
+    /// LEDs.
+    #[cfg(use_lv_led)]
+    pub led: Option<&'static Style>,
+    /// Bars.
+    #[cfg(use_lv_bar)]
+    pub bar: ThemeBar,
+    /// Sliders.
+    #[cfg(use_lv_slider)]
+    pub slider: ThemeSlider,
+    /// Line meters.
+    #[cfg(use_lv_lmeter)]
+    pub lmeter: Option<&'static Style>,
+    /// Gauges.
+    #[cfg(use_lv_gauge)]
+    pub gauge: Option<&'static Style>,
+    /// Arcs.
+    #[cfg(use_lv_arc)]
+    pub arc: Option<&'static Style>,
+    /// Spinners.
+    #[cfg(use_lv_preload)]
+    pub preload: Option<&'static Style>,
+    /// Switches.
+    #[cfg(use_lv_sw)]
+    pub sw: ThemeSwitch,
+    /// Charts.
+    #[cfg(use_lv_chart)]
+    pub chart: Option<&'static Style>,
+    /// Calendars.
+    #[cfg(use_lv_calendar)]
+    pub calendar: ThemeCalendar,
+    /// Checkboxes.
+    #[cfg(use_lv_cb)]
+    pub cb: ThemeCheckbox,
+    /// Button matrices.
+    #[cfg(use_lv_btnm)]
+    pub btnm: ThemeButtonMatrix,
+    /// Keyboards.
+    #[cfg(use_lv_kb)]
+    pub kb: ThemeButtonMatrix,
+    /// Message boxes.
+    #[cfg(use_lv_mbox)]
+    pub mbox: ThemeMessageBox,
+    /// Pages.
+    #[cfg(use_lv_page)]
+    pub page: ThemePage,
+    /// Text areas.
+    #[cfg(use_lv_ta)]
+    pub ta: ThemeTextArea,
+    /// Lists.
+    #[cfg(use_lv_list)]
+    pub list: ThemeList,
+    /// Drop down lists.
+    #[cfg(use_lv_ddlist)]
+    pub ddlist: ThemeDropDown,
+    /// Rollers.
+    #[cfg(use_lv_roller)]
+    pub roller: ThemeRoller,
+    /// Tab views.
+    #[cfg(use_lv_tabview)]
+    pub tabview: ThemeTabView,
+    /// Windows.
+    #[cfg(use_lv_win)]
+    pub win: ThemeWindow,
+}
+
+// The layout of `Theme` must match lv_theme_t, whose offsets are checked by
+// `check_layout`.
+#[allow(dead_code)]
+const THEME_SIZE_CHECK: [(); mem::size_of::<lv_theme_t>()] = [(); mem::size_of::<Theme>()];
+
+/// Check the offsets of the styles of `Theme` match lv_theme_t. They can only
+/// be computed at run time, but they are constant so the checks are optimized
+/// out.
+#[inline]
+fn check_layout() {
+    let theme: Theme = unsafe { mem::zeroed() };
+    let lv_theme: lv_theme_t = unsafe { mem::zeroed() };
+    let theme_base = &theme as *const Theme as usize;
+    let lv_theme_base = &lv_theme as *const lv_theme_t as usize;
+    let mut same = true;
+    macro_rules! check_offset {
+        ($($($field:ident).+),+) => {
+            $(
+                same &= &theme.$($field).+ as *const _ as usize - theme_base
+                    == &lv_theme.$($field).+ as *const _ as usize - lv_theme_base;
+            )+
+        };
+    }
+    check_offset!(bg, panel);
+    #[cfg(use_lv_cont)]
+    check_offset!(cont);
+    #[cfg(use_lv_btn)]
+    check_offset!(btn.rel, btn.pr, btn.tgl_rel, btn.tgl_pr, btn.ina);
+    #[cfg(use_lv_imgbtn)]
+    check_offset!(
+        imgbtn.rel,
+        imgbtn.pr,
+        imgbtn.tgl_rel,
+        imgbtn.tgl_pr,
+        imgbtn.ina
+    );
+    #[cfg(use_lv_label)]
+    check_offset!(label.prim, label.sec, label.hint);
+    #[cfg(use_lv_img)]
+    check_offset!(img.light, img.dark);
+    #[cfg(use_lv_line)]
+    check_offset!(line.decor);
+    #[cfg(use_lv_led)]
+    check_offset!(led);
+    #[cfg(use_lv_bar)]
+    check_offset!(bar.bg, bar.indic);
+    #[cfg(use_lv_slider)]
+    check_offset!(slider.bg, slider.indic, slider.knob);
+    #[cfg(use_lv_lmeter)]
+    check_offset!(lmeter);
+    #[cfg(use_lv_gauge)]
+    check_offset!(gauge);
+    #[cfg(use_lv_arc)]
+    check_offset!(arc);
+    #[cfg(use_lv_preload)]
+    check_offset!(preload);
+    #[cfg(use_lv_sw)]
+    check_offset!(sw.bg, sw.indic, sw.knob_off, sw.knob_on);
+    #[cfg(use_lv_chart)]
+    check_offset!(chart);
+    #[cfg(use_lv_calendar)]
+    check_offset!(
+        calendar.bg,
+        calendar.header,
+        calendar.header_pr,
+        calendar.day_names,
+        calendar.highlighted_days,
+        calendar.inactive_days,
+        calendar.week_box,
+        calendar.today_box
+    );
+    #[cfg(use_lv_cb)]
+    check_offset!(
+        cb.bg,
+        cb.box_.rel,
+        cb.box_.pr,
+        cb.box_.tgl_rel,
+        cb.box_.tgl_pr,
+        cb.box_.ina
+    );
+    #[cfg(use_lv_btnm)]
+    check_offset!(
+        btnm.bg,
+        btnm.btn.rel,
+        btnm.btn.pr,
+        btnm.btn.tgl_rel,
+        btnm.btn.tgl_pr,
+        btnm.btn.ina
+    );
+    #[cfg(use_lv_kb)]
+    check_offset!(
+        kb.bg,
+        kb.btn.rel,
+        kb.btn.pr,
+        kb.btn.tgl_rel,
+        kb.btn.tgl_pr,
+        kb.btn.ina
+    );
+    #[cfg(use_lv_mbox)]
+    check_offset!(mbox.bg, mbox.btn.bg, mbox.btn.rel, mbox.btn.pr);
+    #[cfg(use_lv_page)]
+    check_offset!(page.bg, page.scrl, page.sb);
+    #[cfg(use_lv_ta)]
+    check_offset!(ta.area, ta.oneline, ta.cursor, ta.sb);
+    #[cfg(use_lv_list)]
+    check_offset!(
+        list.bg,
+        list.scrl,
+        list.sb,
+        list.btn.rel,
+        list.btn.pr,
+        list.btn.tgl_rel,
+        list.btn.tgl_pr,
+        list.btn.ina
+    );
+    #[cfg(use_lv_ddlist)]
+    check_offset!(ddlist.bg, ddlist.sel, ddlist.sb);
+    #[cfg(use_lv_roller)]
+    check_offset!(roller.bg, roller.sel);
+    #[cfg(use_lv_tabview)]
+    check_offset!(
+        tabview.bg,
+        tabview.indic,
+        tabview.btn.bg,
+        tabview.btn.rel,
+        tabview.btn.pr,
+        tabview.btn.tgl_rel,
+        tabview.btn.tgl_pr
+    );
+    #[cfg(use_lv_win)]
+    check_offset!(
+        win.bg,
+        win.sb,
+        win.header,
+        win.content.bg,
+        win.content.scrl,
+        win.btn.rel,
+        win.btn.pr
+    );
+    assert!(same, "Theme layout mismatch");
 }
 
 impl Theme {
     /// Get the current system theme.
     #[inline]
     pub fn get_current() -> Option<&'static Theme> {
+        check_layout();
         unsafe {
             let theme = lv_theme_get_current();
             if theme.is_null() {
@@ -25,35 +269,471 @@ impl Theme {
         }
     }
 
+    /// Initialize the default theme, built from the built-in styles, with a
+    /// base hue from 0 to 360 and a font.
+    ///
+    /// Each built-in theme can only be initialized once, the init functions
+    /// return `None` when called again.
+    #[cfg(use_lv_theme_default)]
+    #[inline]
+    pub fn init_default(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_default_init, hue, font) }
+    }
+
+    /// Initialize the alien theme, dark and futuristic.
+    #[cfg(use_lv_theme_alien)]
+    #[inline]
+    pub fn init_alien(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_alien_init, hue, font) }
+    }
+
+    /// Initialize the night theme, dark and elegant.
+    #[cfg(use_lv_theme_night)]
+    #[inline]
+    pub fn init_night(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_night_init, hue, font) }
+    }
+
+    /// Initialize the mono theme, for monochrome displays.
+    #[cfg(use_lv_theme_mono)]
+    #[inline]
+    pub fn init_mono(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_mono_init, hue, font) }
+    }
+
+    /// Initialize the material theme, flat with bold colors and light
+    /// shadows.
+    #[cfg(use_lv_theme_material)]
+    #[inline]
+    pub fn init_material(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_material_init, hue, font) }
+    }
+
+    /// Initialize the zen theme, peaceful and mainly light.
+    #[cfg(use_lv_theme_zen)]
+    #[inline]
+    pub fn init_zen(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_zen_init, hue, font) }
+    }
+
+    /// Initialize the nemo theme, water-like.
+    #[cfg(use_lv_theme_nemo)]
+    #[inline]
+    pub fn init_nemo(hue: u16, font: Font) -> Option<&'static Theme> {
+        static mut READY: bool = false;
+        unsafe { Theme::init(&mut READY, lv_theme_nemo_init, hue, font) }
+    }
+
     /// Set the theme as the current theme for the system.
     #[inline]
     pub fn set_current(theme: &'static Theme) {
-        let theme = unsafe { &theme.lv_theme as *const _ as *mut lv_theme_t };
+        check_layout();
+        let theme = theme as *const Theme as *mut lv_theme_t;
         unsafe {
             lv_theme_set_current(theme);
         }
     }
 
-    #[inline]
-    pub const fn new_empty() -> Self {
-        Theme {
-            empty: [0u8; THEME_SIZE],
+    /// Create a theme giving the objects the built-in styles they get without
+    /// a theme. The parts lvgl leaves unset, such as the labels and the
+    /// cursor of the text areas, get the plain built-in styles.
+    pub fn new_from_builtins() -> Self {
+        unsafe {
+            let transp = builtin(&mut lv_style_transp);
+            let plain = builtin(&mut lv_style_plain);
+            let plain_color = builtin(&mut lv_style_plain_color);
+            let pretty = builtin(&mut lv_style_pretty);
+            let pretty_color = builtin(&mut lv_style_pretty_color);
+            let btn = ThemeButton {
+                rel: builtin(&mut lv_style_btn_rel),
+                pr: builtin(&mut lv_style_btn_pr),
+                tgl_rel: builtin(&mut lv_style_btn_tgl_rel),
+                tgl_pr: builtin(&mut lv_style_btn_tgl_pr),
+                ina: builtin(&mut lv_style_btn_ina),
+            };
+            Theme {
+                bg: builtin(&mut lv_style_scr),
+                panel: pretty,
+                #[cfg(use_lv_cont)]
+                cont: pretty,
+                #[cfg(use_lv_btn)]
+                btn,
+                #[cfg(use_lv_imgbtn)]
+                imgbtn: btn,
+                #[cfg(use_lv_label)]
+                label: ThemeLabel {
+                    prim: plain,
+                    sec: pretty,
+                    hint: pretty,
+                },
+                #[cfg(use_lv_img)]
+                img: ThemeImage {
+                    light: plain,
+                    dark: plain,
+                },
+                #[cfg(use_lv_line)]
+                line: ThemeLine { decor: plain },
+                #[cfg(use_lv_led)]
+                led: pretty_color,
+                #[cfg(use_lv_bar)]
+                bar: ThemeBar {
+                    bg: pretty,
+                    indic: pretty_color,
+                },
+                #[cfg(use_lv_slider)]
+                slider: ThemeSlider {
+                    bg: pretty,
+                    indic: pretty_color,
+                    knob: pretty,
+                },
+                #[cfg(use_lv_lmeter)]
+                lmeter: pretty_color,
+                #[cfg(use_lv_gauge)]
+                gauge: pretty_color,
+                #[cfg(use_lv_arc)]
+                arc: plain_color,
+                #[cfg(use_lv_preload)]
+                preload: pretty_color,
+                #[cfg(use_lv_sw)]
+                sw: ThemeSwitch {
+                    bg: pretty,
+                    indic: pretty_color,
+                    knob_off: pretty,
+                    knob_on: pretty,
+                },
+                #[cfg(use_lv_chart)]
+                chart: pretty,
+                #[cfg(use_lv_calendar)]
+                calendar: ThemeCalendar {
+                    bg: pretty,
+                    header: plain_color,
+                    header_pr: pretty_color,
+                    day_names: pretty,
+                    highlighted_days: plain_color,
+                    inactive_days: btn.ina,
+                    week_box: plain_color,
+                    today_box: pretty_color,
+                },
+                #[cfg(use_lv_cb)]
+                cb: ThemeCheckbox {
+                    bg: transp,
+                    box_: ThemeButton { rel: pretty, ..btn },
+                },
+                #[cfg(use_lv_btnm)]
+                btnm: ThemeButtonMatrix { bg: pretty, btn },
+                #[cfg(use_lv_kb)]
+                kb: ThemeButtonMatrix { bg: pretty, btn },
+                #[cfg(use_lv_mbox)]
+                mbox: ThemeMessageBox {
+                    bg: pretty,
+                    btn: ThemeMessageBoxButtons {
+                        bg: transp,
+                        rel: btn.rel,
+                        pr: btn.pr,
+                    },
+                },
+                #[cfg(use_lv_page)]
+                page: ThemePage {
+                    bg: pretty_color,
+                    scrl: pretty,
+                    sb: pretty_color,
+                },
+                #[cfg(use_lv_ta)]
+                ta: ThemeTextArea {
+                    area: pretty,
+                    oneline: pretty,
+                    cursor: pretty_color,
+                    sb: pretty_color,
+                },
+                #[cfg(use_lv_list)]
+                list: ThemeList {
+                    bg: builtin(&mut lv_style_transp_fit),
+                    scrl: pretty,
+                    sb: pretty_color,
+                    btn,
+                },
+                #[cfg(use_lv_ddlist)]
+                ddlist: ThemeDropDown {
+                    bg: pretty,
+                    sel: plain_color,
+                    sb: pretty_color,
+                },
+                #[cfg(use_lv_roller)]
+                roller: ThemeRoller {
+                    bg: pretty,
+                    sel: plain_color,
+                },
+                #[cfg(use_lv_tabview)]
+                tabview: ThemeTabView {
+                    bg: plain,
+                    indic: plain_color,
+                    btn: ThemeTabButtons {
+                        bg: pretty,
+                        rel: btn.rel,
+                        pr: btn.pr,
+                        tgl_rel: btn.tgl_rel,
+                        tgl_pr: btn.tgl_pr,
+                    },
+                },
+                #[cfg(use_lv_win)]
+                win: ThemeWindow {
+                    bg: plain,
+                    sb: pretty_color,
+                    header: plain_color,
+                    content: ThemeWindowContent {
+                        bg: builtin(&mut lv_style_transp_tight),
+                        scrl: transp,
+                    },
+                    btn: ThemeWindowButtons {
+                        rel: btn.rel,
+                        pr: btn.pr,
+                    },
+                },
+            }
         }
     }
 }
 
-impl Deref for Theme {
-    type Target = lv_theme_t;
-
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { &self.lv_theme }
+impl Theme {
+    /// Call the init function of a built-in theme, unless `ready` tells it
+    /// has been called already. Each built-in theme is a single static
+    /// object, which another call would overwrite while it is referenced.
+    #[cfg(any(
+        use_lv_theme_alien,
+        use_lv_theme_default,
+        use_lv_theme_material,
+        use_lv_theme_mono,
+        use_lv_theme_nemo,
+        use_lv_theme_night,
+        use_lv_theme_zen
+    ))]
+    unsafe fn init(
+        ready: &mut bool,
+        init: unsafe extern "C" fn(u16, *mut lv_font_t) -> *mut lv_theme_t,
+        hue: u16,
+        font: Font,
+    ) -> Option<&'static Theme> {
+        assert!(hue <= 360, "Hue out of range");
+        check_layout();
+        if *ready {
+            return None;
+        }
+        *ready = true;
+        let font: *const lv_font_t = font.into();
+        Some(&*(init(hue, font as *mut lv_font_t) as *mut Theme))
     }
 }
 
-impl DerefMut for Theme {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        unsafe { &mut self.lv_theme }
-    }
+/// Styles of a button in each of its states.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeButton {
+    pub rel: Option<&'static Style>,
+    pub pr: Option<&'static Style>,
+    pub tgl_rel: Option<&'static Style>,
+    pub tgl_pr: Option<&'static Style>,
+    pub ina: Option<&'static Style>,
+}
+
+/// Styles of the labels: primary, secondary and hint.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeLabel {
+    pub prim: Option<&'static Style>,
+    pub sec: Option<&'static Style>,
+    pub hint: Option<&'static Style>,
+}
+
+/// Styles of the images on light and dark backgrounds.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeImage {
+    pub light: Option<&'static Style>,
+    pub dark: Option<&'static Style>,
+}
+
+/// Style of the decorative lines.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeLine {
+    pub decor: Option<&'static Style>,
+}
+
+/// Styles of a bar.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeBar {
+    pub bg: Option<&'static Style>,
+    pub indic: Option<&'static Style>,
+}
+
+/// Styles of a slider.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeSlider {
+    pub bg: Option<&'static Style>,
+    pub indic: Option<&'static Style>,
+    pub knob: Option<&'static Style>,
+}
+
+/// Styles of a switch.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeSwitch {
+    pub bg: Option<&'static Style>,
+    pub indic: Option<&'static Style>,
+    pub knob_off: Option<&'static Style>,
+    pub knob_on: Option<&'static Style>,
+}
+
+/// Styles of a calendar.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeCalendar {
+    pub bg: Option<&'static Style>,
+    pub header: Option<&'static Style>,
+    pub header_pr: Option<&'static Style>,
+    pub day_names: Option<&'static Style>,
+    pub highlighted_days: Option<&'static Style>,
+    pub inactive_days: Option<&'static Style>,
+    pub week_box: Option<&'static Style>,
+    pub today_box: Option<&'static Style>,
+}
+
+/// Styles of a checkbox.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeCheckbox {
+    pub bg: Option<&'static Style>,
+    pub box_: ThemeButton,
+}
+
+/// Styles of a button matrix or a keyboard.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeButtonMatrix {
+    pub bg: Option<&'static Style>,
+    pub btn: ThemeButton,
+}
+
+/// Styles of a message box.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeMessageBox {
+    pub bg: Option<&'static Style>,
+    pub btn: ThemeMessageBoxButtons,
+}
+
+/// Styles of the buttons of a message box.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeMessageBoxButtons {
+    pub bg: Option<&'static Style>,
+    pub rel: Option<&'static Style>,
+    pub pr: Option<&'static Style>,
+}
+
+/// Styles of a page.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemePage {
+    pub bg: Option<&'static Style>,
+    pub scrl: Option<&'static Style>,
+    pub sb: Option<&'static Style>,
+}
+
+/// Styles of a text area.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeTextArea {
+    pub area: Option<&'static Style>,
+    pub oneline: Option<&'static Style>,
+    pub cursor: Option<&'static Style>,
+    pub sb: Option<&'static Style>,
+}
+
+/// Styles of a list.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeList {
+    pub bg: Option<&'static Style>,
+    pub scrl: Option<&'static Style>,
+    pub sb: Option<&'static Style>,
+    pub btn: ThemeButton,
+}
+
+/// Styles of a drop down list.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeDropDown {
+    pub bg: Option<&'static Style>,
+    pub sel: Option<&'static Style>,
+    pub sb: Option<&'static Style>,
+}
+
+/// Styles of a roller.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeRoller {
+    pub bg: Option<&'static Style>,
+    pub sel: Option<&'static Style>,
+}
+
+/// Styles of a tab view.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeTabView {
+    pub bg: Option<&'static Style>,
+    pub indic: Option<&'static Style>,
+    pub btn: ThemeTabButtons,
+}
+
+/// Styles of the tab buttons of a tab view.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeTabButtons {
+    pub bg: Option<&'static Style>,
+    pub rel: Option<&'static Style>,
+    pub pr: Option<&'static Style>,
+    pub tgl_rel: Option<&'static Style>,
+    pub tgl_pr: Option<&'static Style>,
+}
+
+/// Styles of a window.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeWindow {
+    pub bg: Option<&'static Style>,
+    pub sb: Option<&'static Style>,
+    pub header: Option<&'static Style>,
+    pub content: ThemeWindowContent,
+    pub btn: ThemeWindowButtons,
+}
+
+/// Styles of the content of a window.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeWindowContent {
+    pub bg: Option<&'static Style>,
+    pub scrl: Option<&'static Style>,
+}
+
+/// Styles of the header buttons of a window.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct ThemeWindowButtons {
+    pub rel: Option<&'static Style>,
+    pub pr: Option<&'static Style>,
+}
+
+/// Wrap a built-in style of lvgl.
+#[inline]
+unsafe fn builtin(style: *mut lv_style_t) -> Option<&'static Style> {
+    Some(Style::from_lv_style(style))
 }
@@ -0,0 +1,76 @@
+use crate::obj::{Child, ObjT, Owned};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(transparent)]
+pub struct Arc(*mut lv_obj_t);
+
+impl Arc {
+    /// Create a new arc on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new arc as a child of `parent`, optionally copying another
+    /// arc.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Arc>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let arc = lv_arc_create(parent.as_lv_obj(), copy);
+            if arc.is_null() {
+                None
+            } else {
+                Some(Child::new(Arc(arc)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the start and end angles of the arc in degrees.
+    #[inline]
+    pub fn set_angles(&mut self, start: u16, end: u16) {
+        unsafe {
+            lv_arc_set_angles(self.0, start, end);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the start angle of the arc.
+    #[inline]
+    pub fn get_angle_start(&self) -> u16 {
+        unsafe { lv_arc_get_angle_start(self.0) }
+    }
+
+    /// Get the end angle of the arc.
+    #[inline]
+    pub fn get_angle_end(&self) -> u16 {
+        unsafe { lv_arc_get_angle_end(self.0) }
+    }
+}
+
+impl ObjT for Arc {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Arc(obj)
+    }
+}
+
+impl Drop for Arc {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BarStyle {
+    Background = LV_BAR_STYLE_BG as lv_bar_style_t,
+    Indicator = LV_BAR_STYLE_INDIC as lv_bar_style_t,
+}
+
+#[repr(transparent)]
+pub struct Bar(*mut lv_obj_t);
+
+impl Bar {
+    /// Create a new bar on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new bar as a child of `parent`, optionally copying another
+    /// bar.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Bar>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let bar = lv_bar_create(parent.as_lv_obj(), copy);
+            if bar.is_null() {
+                None
+            } else {
+                Some(Child::new(Bar(bar)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set a new value on the bar.
+    #[inline]
+    pub fn set_value(&mut self, value: i16) {
+        unsafe {
+            lv_bar_set_value(self.0, value);
+        }
+    }
+
+    /// Set a new value with animation on the bar.
+    #[inline]
+    pub fn set_value_anim(&mut self, value: i16, anim_time: u16) {
+        unsafe {
+            lv_bar_set_value_anim(self.0, value, anim_time);
+        }
+    }
+
+    /// Set minimum and the maximum values of the bar.
+    #[inline]
+    pub fn set_range(&mut self, min: i16, max: i16) {
+        unsafe {
+            lv_bar_set_range(self.0, min, max);
+        }
+    }
+
+    /// Set the style of a part of the bar.
+    #[inline]
+    pub fn set_part_style(&mut self, part: BarStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_bar_set_style(self.0, part as lv_bar_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the value of the bar.
+    #[inline]
+    pub fn get_value(&self) -> i16 {
+        unsafe { lv_bar_get_value(self.0) }
+    }
+
+    /// Get the minimum value of the bar.
+    #[inline]
+    pub fn get_min_value(&self) -> i16 {
+        unsafe { lv_bar_get_min_value(self.0) }
+    }
+
+    /// Get the maximum value of the bar.
+    #[inline]
+    pub fn get_max_value(&self) -> i16 {
+        unsafe { lv_bar_get_max_value(self.0) }
+    }
+}
+
+impl ObjT for Bar {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Bar(obj)
+    }
+}
+
+impl Drop for Bar {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ButtonState {
+    Released = LV_BTN_STATE_REL as lv_btn_state_t,
+    Pressed = LV_BTN_STATE_PR as lv_btn_state_t,
+    ToggledReleased = LV_BTN_STATE_TGL_REL as lv_btn_state_t,
+    ToggledPressed = LV_BTN_STATE_TGL_PR as lv_btn_state_t,
+    Inactive = LV_BTN_STATE_INA as lv_btn_state_t,
+}
+
+impl ButtonState {
+    #[inline]
+    pub(crate) fn from_lv_state(state: lv_btn_state_t) -> Self {
+        match state as u32 {
+            LV_BTN_STATE_REL => ButtonState::Released,
+            LV_BTN_STATE_PR => ButtonState::Pressed,
+            LV_BTN_STATE_TGL_REL => ButtonState::ToggledReleased,
+            LV_BTN_STATE_TGL_PR => ButtonState::ToggledPressed,
+            _ => ButtonState::Inactive,
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ButtonStyle {
+    Released = LV_BTN_STYLE_REL as lv_btn_style_t,
+    Pressed = LV_BTN_STYLE_PR as lv_btn_style_t,
+    ToggledReleased = LV_BTN_STYLE_TGL_REL as lv_btn_style_t,
+    ToggledPressed = LV_BTN_STYLE_TGL_PR as lv_btn_style_t,
+    Inactive = LV_BTN_STYLE_INA as lv_btn_style_t,
+}
+
+#[repr(transparent)]
+pub struct Button(*mut lv_obj_t);
+
+impl Button {
+    /// Create a new button on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new button as a child of `parent`, optionally copying another
+    /// button.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Button>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let button = lv_btn_create(parent.as_lv_obj(), copy);
+            if button.is_null() {
+                None
+            } else {
+                Some(Child::new(Button(button)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Enable the toggled states.
+    #[inline]
+    pub fn set_toggle(&mut self, enabled: bool) {
+        unsafe {
+            lv_btn_set_toggle(self.0, enabled);
+        }
+    }
+
+    /// Set the state of the button.
+    #[inline]
+    pub fn set_state(&mut self, state: ButtonState) {
+        unsafe {
+            lv_btn_set_state(self.0, state as lv_btn_state_t);
+        }
+    }
+
+    /// Toggle the state of the button (ON->OFF, OFF->ON).
+    #[inline]
+    pub fn toggle(&mut self) {
+        unsafe {
+            lv_btn_toggle(self.0);
+        }
+    }
+
+    /// Set time of the ink effect (draw a circle on click to animate in the
+    /// new state).
+    #[inline]
+    pub fn set_ink_in_time(&mut self, time: u16) {
+        unsafe {
+            lv_btn_set_ink_in_time(self.0, time);
+        }
+    }
+
+    /// Set the wait time before the ink disappears.
+    #[inline]
+    pub fn set_ink_wait_time(&mut self, time: u16) {
+        unsafe {
+            lv_btn_set_ink_wait_time(self.0, time);
+        }
+    }
+
+    /// Set time of the ink out effect (animate to the released state).
+    #[inline]
+    pub fn set_ink_out_time(&mut self, time: u16) {
+        unsafe {
+            lv_btn_set_ink_out_time(self.0, time);
+        }
+    }
+
+    /// Set the style of a part of the button.
+    #[inline]
+    pub fn set_part_style(&mut self, part: ButtonStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_btn_set_style(self.0, part as lv_btn_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the current state of the button.
+    #[inline]
+    pub fn get_state(&self) -> ButtonState {
+        ButtonState::from_lv_state(unsafe { lv_btn_get_state(self.0) })
+    }
+
+    /// Get the toggle enable attribute of the button.
+    #[inline]
+    pub fn get_toggle(&self) -> bool {
+        unsafe { lv_btn_get_toggle(self.0) }
+    }
+}
+
+impl ObjT for Button {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Button(obj)
+    }
+}
+
+impl Drop for Button {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+/// Check whether a button, or an object built on one, is toggled.
+pub(crate) unsafe fn is_toggled(btn: *mut lv_obj_t) -> bool {
+    let state = u32::from(lv_btn_get_state(btn));
+    state == LV_BTN_STATE_TGL_REL || state == LV_BTN_STATE_TGL_PR
+}
@@ -0,0 +1,175 @@
+use crate::callback;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+
+use core::mem::ManuallyDrop;
+use core::ptr;
+use core::slice;
+use ffi::*;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ButtonMatrixStyle {
+    Background = LV_BTNM_STYLE_BG as lv_btnm_style_t,
+    Released = LV_BTNM_STYLE_BTN_REL as lv_btnm_style_t,
+    Pressed = LV_BTNM_STYLE_BTN_PR as lv_btnm_style_t,
+    ToggledReleased = LV_BTNM_STYLE_BTN_TGL_REL as lv_btnm_style_t,
+    ToggledPressed = LV_BTNM_STYLE_BTN_TGL_PR as lv_btnm_style_t,
+    Inactive = LV_BTNM_STYLE_BTN_INA as lv_btnm_style_t,
+}
+
+/// An entry of a button map: the nul terminated text of a button.
+///
+/// The text may start with a control byte, `\x80` ored with the relative
+/// width of the button (1 to 7) and the flags `\x08` (hidden), `\x10` (no
+/// long press repeat) and `\x20` (inactive). `NEW_LINE` starts a new row of
+/// buttons and `END` has to terminate the map.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct ButtonMapEntry(*const u8);
+
+// The entries only point to static texts.
+unsafe impl Sync for ButtonMapEntry {}
+
+impl ButtonMapEntry {
+    pub const NEW_LINE: ButtonMapEntry = ButtonMapEntry::new(b"\n\0");
+    pub const END: ButtonMapEntry = ButtonMapEntry::new(b"\0");
+
+    #[inline]
+    pub const fn new(text: &'static [u8]) -> Self {
+        ButtonMapEntry(text.as_ptr())
+    }
+}
+
+/// Check that a map ends with `ButtonMapEntry::END`, where lvgl stops
+/// reading it.
+#[inline]
+pub(crate) fn assert_terminated(map: &[ButtonMapEntry]) {
+    assert!(
+        map.last().map_or(false, |entry| unsafe { *entry.0 } == 0),
+        "Button map not terminated by END"
+    );
+}
+
+#[repr(transparent)]
+pub struct ButtonMatrix(*mut lv_obj_t);
+
+impl ButtonMatrix {
+    /// Create a new button matrix on `parent`, deleted when the returned handle
+    /// is dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new button matrix as a child of `parent`, optionally copying
+    /// another button matrix.
+    #[inline]
+    pub fn create<'a>(
+        parent: &'a impl ObjT,
+        copy: Option<&ButtonMatrix>,
+    ) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let btnm = lv_btnm_create(parent.as_lv_obj(), copy);
+            if btnm.is_null() {
+                None
+            } else {
+                Some(Child::new(ButtonMatrix(btnm)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set a new map. Buttons will be created/deleted according to the map.
+    /// The map is not copied, so it has to be static.
+    #[inline]
+    pub fn set_map(&mut self, map: &'static [ButtonMapEntry]) {
+        assert_terminated(map);
+        unsafe {
+            lv_btnm_set_map(self.0, map.as_ptr() as *mut *const c_char);
+        }
+    }
+
+    /// Set a function to be called with the text of a button when it is
+    /// released. The closure must not capture any variables or delete the
+    /// button matrix.
+    #[inline]
+    pub fn set_action<F>(&mut self, action: F)
+    where
+        F: Fn(&mut ButtonMatrix, &[u8]) + Copy + 'static,
+    {
+        callback::assert_zero_sized(&action);
+        unsafe {
+            lv_btnm_set_action(self.0, Some(action_trampoline::<F>));
+        }
+    }
+
+    /// Enable or disable button toggling. The button with index `id` is
+    /// toggled, the other buttons are released.
+    #[inline]
+    pub fn set_toggle(&mut self, enabled: bool, id: u16) {
+        unsafe {
+            lv_btnm_set_toggle(self.0, enabled, id);
+        }
+    }
+
+    /// Set the style of a part of the button matrix.
+    #[inline]
+    pub fn set_part_style(&mut self, part: ButtonMatrixStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_btnm_set_style(self.0, part as lv_btnm_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the index of the toggled button, if toggling is enabled.
+    #[inline]
+    pub fn get_toggled(&self) -> Option<u16> {
+        let id = unsafe { lv_btnm_get_toggled(self.0) };
+        if id as u32 == LV_BTNM_BTN_NONE {
+            None
+        } else {
+            Some(id)
+        }
+    }
+}
+
+impl ObjT for ButtonMatrix {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        ButtonMatrix(obj)
+    }
+}
+
+impl Drop for ButtonMatrix {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+unsafe extern "C" fn action_trampoline<F>(btnm: *mut lv_obj_t, txt: *const c_char) -> lv_res_t
+where
+    F: Fn(&mut ButtonMatrix, &[u8]) + Copy,
+{
+    let action: F = callback::recreate();
+    let mut len = 0;
+    while *txt.add(len) != 0 {
+        len += 1;
+    }
+    let txt = slice::from_raw_parts(txt as *const u8, len);
+    action(&mut ManuallyDrop::new(ButtonMatrix(btnm)), txt);
+    LV_RES_OK as lv_res_t
+}
@@ -0,0 +1,228 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+
+use core::ptr;
+use ffi::*;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CalendarStyle {
+    Background = LV_CALENDAR_STYLE_BG as lv_calendar_style_t,
+    Header = LV_CALENDAR_STYLE_HEADER as lv_calendar_style_t,
+    HeaderPressed = LV_CALENDAR_STYLE_HEADER_PR as lv_calendar_style_t,
+    DayNames = LV_CALENDAR_STYLE_DAY_NAMES as lv_calendar_style_t,
+    HighlightedDays = LV_CALENDAR_STYLE_HIGHLIGHTED_DAYS as lv_calendar_style_t,
+    InactiveDays = LV_CALENDAR_STYLE_INACTIVE_DAYS as lv_calendar_style_t,
+    WeekBox = LV_CALENDAR_STYLE_WEEK_BOX as lv_calendar_style_t,
+    TodayBox = LV_CALENDAR_STYLE_TODAY_BOX as lv_calendar_style_t,
+}
+
+/// A date of the calendar.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Date {
+    pub year: u16,
+    /// The month, from 1 to 12.
+    pub month: i8,
+    /// The day of the month, from 1 to 31.
+    pub day: i8,
+}
+
+impl Date {
+    #[inline]
+    pub const fn new(year: u16, month: i8, day: i8) -> Self {
+        Date { year, month, day }
+    }
+}
+
+impl From<lv_calendar_date_t> for Date {
+    #[inline]
+    fn from(date: lv_calendar_date_t) -> Self {
+        Date::new(date.year, date.month, date.day)
+    }
+}
+
+impl From<Date> for lv_calendar_date_t {
+    #[inline]
+    fn from(date: Date) -> Self {
+        lv_calendar_date_t {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+        }
+    }
+}
+
+/// The nul terminated name of a day or a month.
+///
+/// The names given to a calendar have to be static, e.g.
+/// `static DAY_NAMES: [CalendarName; 7] = [calendar_name!("Su"), ...];`.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct CalendarName(*const u8);
+
+// The names only point to static texts.
+unsafe impl Sync for CalendarName {}
+
+impl CalendarName {
+    /// Wrap a static name, given with the terminating nul.
+    #[inline]
+    pub fn new(name: &'static [u8]) -> Self {
+        assert_eq!(name.last(), Some(&0), "Calendar name not nul terminated");
+        CalendarName(name.as_ptr())
+    }
+
+    /// Wrap a static name without checking it.
+    ///
+    /// # Safety
+    ///
+    /// `name` must point to a static nul terminated string.
+    #[doc(hidden)]
+    #[inline]
+    pub const unsafe fn from_ptr(name: *const u8) -> Self {
+        CalendarName(name)
+    }
+}
+
+/// Create a `CalendarName` from a string literal in a constant expression.
+#[macro_export]
+macro_rules! calendar_name {
+    ($name:expr) => {
+        unsafe { $crate::CalendarName::from_ptr(concat!($name, "\0") as *const str as *const u8) }
+    };
+}
+
+/// A calendar showing a month, sending `Event::ValueChanged` to its event
+/// handler when the user has clicked a date.
+#[repr(transparent)]
+pub struct Calendar(*mut lv_obj_t);
+
+impl Calendar {
+    /// Create a new calendar on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new calendar as a child of `parent`, optionally copying
+    /// another calendar.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Calendar>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_calendar_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(calendar: *mut lv_obj_t) -> Option<Self> {
+        if calendar.is_null() {
+            None
+        } else {
+            let click = LV_CALENDAR_ACTION_CLICK as lv_calendar_action_t;
+            lv_calendar_set_action(calendar, click, Some(event::value_changed_action));
+            Some(Calendar(calendar))
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the date of today.
+    #[inline]
+    pub fn set_today_date(&mut self, date: Date) {
+        let mut date = date.into();
+        unsafe {
+            lv_calendar_set_today_date(self.0, &mut date);
+        }
+    }
+
+    /// Set the date whose month is shown.
+    #[inline]
+    pub fn set_showed_date(&mut self, date: Date) {
+        let mut date = date.into();
+        unsafe {
+            lv_calendar_set_showed_date(self.0, &mut date);
+        }
+    }
+
+    /// Set the highlighted dates. The dates are not copied, so they have to
+    /// be static.
+    #[inline]
+    pub fn set_highlighted_dates(&mut self, dates: &'static [Date]) {
+        unsafe {
+            let dates_ptr = dates.as_ptr() as *mut lv_calendar_date_t;
+            lv_calendar_set_highlighted_dates(self.0, dates_ptr, dates.len() as u16);
+        }
+    }
+
+    /// Set the names of the days, starting with Sunday. The names are not
+    /// copied, so they have to be static.
+    #[inline]
+    pub fn set_day_names(&mut self, names: &'static [CalendarName; 7]) {
+        unsafe {
+            lv_calendar_set_day_names(self.0, names.as_ptr() as *mut *const c_char);
+        }
+    }
+
+    /// Set the names of the months, starting with January. The names are not
+    /// copied, so they have to be static.
+    #[inline]
+    pub fn set_month_names(&mut self, names: &'static [CalendarName; 12]) {
+        unsafe {
+            lv_calendar_set_month_names(self.0, names.as_ptr() as *mut *const c_char);
+        }
+    }
+
+    /// Set the style of a part of the calendar.
+    #[inline]
+    pub fn set_part_style(&mut self, part: CalendarStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_calendar_set_style(self.0, part as lv_calendar_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the date of today.
+    #[inline]
+    pub fn get_today_date(&self) -> Date {
+        unsafe { (*lv_calendar_get_today_date(self.0)).into() }
+    }
+
+    /// Get the date whose month is shown.
+    #[inline]
+    pub fn get_showed_date(&self) -> Date {
+        unsafe { (*lv_calendar_get_showed_date(self.0)).into() }
+    }
+
+    /// Get the date which has been pressed last.
+    #[inline]
+    pub fn get_pressed_date(&self) -> Date {
+        unsafe { (*lv_calendar_get_pressed_date(self.0)).into() }
+    }
+}
+
+impl ObjT for Calendar {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Calendar(obj)
+    }
+}
+
+impl Drop for Calendar {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
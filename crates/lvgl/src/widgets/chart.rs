@@ -0,0 +1,234 @@
+use crate::color::Color;
+use crate::obj::{Child, ObjT, Owned};
+
+use core::ptr;
+use core::slice;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChartType {
+    /// Connect the points with lines.
+    Line = LV_CHART_TYPE_LINE as lv_chart_type_t,
+    /// Draw a column for each point.
+    Column = LV_CHART_TYPE_COLUMN as lv_chart_type_t,
+    /// Draw only the points.
+    Point = LV_CHART_TYPE_POINT as lv_chart_type_t,
+}
+
+/// A data series of a chart. The series belongs to the chart and is freed
+/// together with it.
+#[derive(Clone, Copy)]
+pub struct ChartSeries {
+    chart: *mut lv_obj_t,
+    series: *mut lv_chart_series_t,
+}
+
+#[repr(transparent)]
+pub struct Chart(*mut lv_obj_t);
+
+impl Chart {
+    /// Create a new chart on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new chart as a child of `parent`, optionally copying another
+    /// chart.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Chart>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let chart = lv_chart_create(parent.as_lv_obj(), copy);
+            if chart.is_null() {
+                None
+            } else {
+                Some(Child::new(Chart(chart)))
+            }
+        }
+    }
+
+    // Series.
+
+    /// Allocate and add a data series to the chart.
+    #[inline]
+    pub fn add_series(&mut self, color: Color) -> Option<ChartSeries> {
+        unsafe {
+            let series = lv_chart_add_series(self.0, color.into());
+            if series.is_null() {
+                None
+            } else {
+                Some(ChartSeries {
+                    chart: self.0,
+                    series,
+                })
+            }
+        }
+    }
+
+    /// Clear the points of a data series.
+    #[inline]
+    pub fn clear_series(&mut self, series: ChartSeries) {
+        unsafe {
+            lv_chart_clear_serie(self.0, self.check(series));
+        }
+    }
+
+    /// Initialize all points of a data series with a value.
+    #[inline]
+    pub fn init_points(&mut self, series: ChartSeries, value: lv_coord_t) {
+        unsafe {
+            lv_chart_init_points(self.0, self.check(series), value);
+        }
+    }
+
+    /// Set the values of all points of a data series. `values` has to hold
+    /// exactly one value for each point.
+    #[inline]
+    pub fn set_points(&mut self, series: ChartSeries, values: &[lv_coord_t]) {
+        assert_eq!(values.len(), self.get_point_count() as usize);
+        unsafe {
+            let values = values.as_ptr() as *mut lv_coord_t;
+            lv_chart_set_points(self.0, self.check(series), values);
+        }
+    }
+
+    /// Shift all points of a data series to the left and set the rightmost
+    /// one to a new value.
+    #[inline]
+    pub fn set_next(&mut self, series: ChartSeries, value: lv_coord_t) {
+        unsafe {
+            lv_chart_set_next(self.0, self.check(series), value);
+        }
+    }
+
+    /// Get the values of the points of a data series.
+    #[inline]
+    pub fn get_points(&self, series: ChartSeries) -> &[lv_coord_t] {
+        unsafe {
+            let points = (*self.check(series)).points;
+            slice::from_raw_parts(points, self.get_point_count() as usize)
+        }
+    }
+
+    #[inline]
+    fn check(&self, series: ChartSeries) -> *mut lv_chart_series_t {
+        assert!(series.chart == self.0, "Series of another chart");
+        series.series
+    }
+
+    // Setter methods.
+
+    /// Set the type of the chart.
+    #[inline]
+    pub fn set_type(&mut self, chart_type: ChartType) {
+        unsafe {
+            lv_chart_set_type(self.0, chart_type as lv_chart_type_t);
+        }
+    }
+
+    /// Set the number of horizontal and vertical division lines.
+    #[inline]
+    pub fn set_div_line_count(&mut self, hdiv: u8, vdiv: u8) {
+        unsafe {
+            lv_chart_set_div_line_count(self.0, hdiv, vdiv);
+        }
+    }
+
+    /// Set the minimal and maximal y values.
+    #[inline]
+    pub fn set_range(&mut self, min: lv_coord_t, max: lv_coord_t) {
+        unsafe {
+            lv_chart_set_range(self.0, min, max);
+        }
+    }
+
+    /// Set the number of points on a data series.
+    #[inline]
+    pub fn set_point_count(&mut self, count: u16) {
+        unsafe {
+            lv_chart_set_point_count(self.0, count);
+        }
+    }
+
+    /// Set the opacity of the data series.
+    #[inline]
+    pub fn set_series_opa(&mut self, opa: lv_opa_t) {
+        unsafe {
+            lv_chart_set_series_opa(self.0, opa);
+        }
+    }
+
+    /// Set the line width or point radius of the data series.
+    #[inline]
+    pub fn set_series_width(&mut self, width: lv_coord_t) {
+        unsafe {
+            lv_chart_set_series_width(self.0, width);
+        }
+    }
+
+    /// Set the dark effect on the bottom of the points or columns.
+    #[inline]
+    pub fn set_series_darking(&mut self, dark_eff: lv_opa_t) {
+        unsafe {
+            lv_chart_set_series_darking(self.0, dark_eff);
+        }
+    }
+
+    /// Refresh the chart if its data series have changed.
+    #[inline]
+    pub fn refresh(&mut self) {
+        unsafe {
+            lv_chart_refresh(self.0);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the number of points on a data series.
+    #[inline]
+    pub fn get_point_count(&self) -> u16 {
+        unsafe { lv_chart_get_point_cnt(self.0) }
+    }
+
+    /// Get the opacity of the data series.
+    #[inline]
+    pub fn get_series_opa(&self) -> lv_opa_t {
+        unsafe { lv_chart_get_series_opa(self.0) }
+    }
+
+    /// Get the line width or point radius of the data series.
+    #[inline]
+    pub fn get_series_width(&self) -> lv_coord_t {
+        unsafe { lv_chart_get_series_width(self.0) }
+    }
+
+    /// Get the dark effect on the bottom of the points or columns.
+    #[inline]
+    pub fn get_series_darking(&self) -> lv_opa_t {
+        unsafe { lv_chart_get_series_darking(self.0) }
+    }
+}
+
+impl ObjT for Chart {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Chart(obj)
+    }
+}
+
+impl Drop for Chart {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
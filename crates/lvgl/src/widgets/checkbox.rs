@@ -0,0 +1,127 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::{button, options};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CheckboxStyle {
+    Background = LV_CB_STYLE_BG as lv_cb_style_t,
+    BoxReleased = LV_CB_STYLE_BOX_REL as lv_cb_style_t,
+    BoxPressed = LV_CB_STYLE_BOX_PR as lv_cb_style_t,
+    BoxToggledReleased = LV_CB_STYLE_BOX_TGL_REL as lv_cb_style_t,
+    BoxToggledPressed = LV_CB_STYLE_BOX_TGL_PR as lv_cb_style_t,
+    BoxInactive = LV_CB_STYLE_BOX_INA as lv_cb_style_t,
+}
+
+/// A check box, sending `Event::ValueChanged` to its event handler when the
+/// user has toggled it.
+#[repr(transparent)]
+pub struct Checkbox(*mut lv_obj_t);
+
+impl Checkbox {
+    /// Create a new check box on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new check box as a child of `parent`, optionally copying
+    /// another check box.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Checkbox>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_cb_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(cb: *mut lv_obj_t) -> Option<Self> {
+        if cb.is_null() {
+            None
+        } else {
+            // NOTE: call the btn function directly.
+            let click = LV_BTN_ACTION_CLICK as lv_btn_action_t;
+            lv_btn_set_action(cb, click, Some(event::value_changed_action));
+            Some(Checkbox(cb))
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the text of the check box, given without terminating nul. Memory
+    /// will be allocated to store the text by the check box.
+    #[inline]
+    pub fn set_text(&mut self, text: &[u8]) {
+        unsafe {
+            options::with_options(&[text], |text| lv_cb_set_text(self.0, text));
+        }
+    }
+
+    /// Set the state of the check box.
+    #[inline]
+    pub fn set_checked(&mut self, checked: bool) {
+        let state = if checked {
+            LV_BTN_STATE_TGL_REL
+        } else {
+            LV_BTN_STATE_REL
+        };
+        unsafe {
+            // NOTE: call the btn function directly.
+            lv_btn_set_state(self.0, state as lv_btn_state_t);
+        }
+    }
+
+    /// Make the check box inactive (disabled).
+    #[inline]
+    pub fn set_inactive(&mut self) {
+        unsafe {
+            // NOTE: call the btn function directly.
+            lv_btn_set_state(self.0, LV_BTN_STATE_INA as lv_btn_state_t);
+        }
+    }
+
+    /// Set the style of a part of the check box.
+    #[inline]
+    pub fn set_part_style(&mut self, part: CheckboxStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_cb_set_style(self.0, part as lv_cb_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get whether the check box is checked.
+    #[inline]
+    pub fn is_checked(&self) -> bool {
+        // A check box is a button, which is toggled while checked.
+        unsafe { button::is_toggled(self.0) }
+    }
+}
+
+impl ObjT for Checkbox {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Checkbox(obj)
+    }
+}
+
+impl Drop for Checkbox {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::obj::{Child, ObjT, Owned};
+
+use core::ptr;
+use lvgl_sys::*;
+
+/// Arrangement of the children of a container.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Layout {
+    /// Keep the children where they have been put.
+    Off = LV_LAYOUT_OFF as lv_layout_t,
+    /// Center the children as a column.
+    Center = LV_LAYOUT_CENTER as lv_layout_t,
+    /// Arrange the children in a left aligned column.
+    ColumnLeft = LV_LAYOUT_COL_L as lv_layout_t,
+    /// Arrange the children in a centered column.
+    ColumnMid = LV_LAYOUT_COL_M as lv_layout_t,
+    /// Arrange the children in a right aligned column.
+    ColumnRight = LV_LAYOUT_COL_R as lv_layout_t,
+    /// Arrange the children in a top aligned row.
+    RowTop = LV_LAYOUT_ROW_T as lv_layout_t,
+    /// Arrange the children in a centered row.
+    RowMid = LV_LAYOUT_ROW_M as lv_layout_t,
+    /// Arrange the children in a bottom aligned row.
+    RowBottom = LV_LAYOUT_ROW_B as lv_layout_t,
+    /// Put as many children in a row as possible and spread them evenly.
+    Pretty = LV_LAYOUT_PRETTY as lv_layout_t,
+    /// Put as many children in a row as possible with the same gaps.
+    Grid = LV_LAYOUT_GRID as lv_layout_t,
+}
+
+impl Layout {
+    #[inline]
+    pub(crate) fn from_lv_layout(layout: lv_layout_t) -> Self {
+        match u32::from(layout) {
+            LV_LAYOUT_CENTER => Layout::Center,
+            LV_LAYOUT_COL_L => Layout::ColumnLeft,
+            LV_LAYOUT_COL_M => Layout::ColumnMid,
+            LV_LAYOUT_COL_R => Layout::ColumnRight,
+            LV_LAYOUT_ROW_T => Layout::RowTop,
+            LV_LAYOUT_ROW_M => Layout::RowMid,
+            LV_LAYOUT_ROW_B => Layout::RowBottom,
+            LV_LAYOUT_PRETTY => Layout::Pretty,
+            LV_LAYOUT_GRID => Layout::Grid,
+            _ => Layout::Off,
+        }
+    }
+}
+
+/// A container arranging its children with a layout and optionally fitting
+/// its size to them.
+#[repr(transparent)]
+pub struct Container(*mut lv_obj_t);
+
+impl Container {
+    /// Create a new container on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new container as a child of `parent`, optionally copying
+    /// another container.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Container>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let cont = lv_cont_create(parent.as_lv_obj(), copy);
+            if cont.is_null() {
+                None
+            } else {
+                Some(Child::new(Container(cont)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the layout of the container.
+    #[inline]
+    pub fn set_layout(&mut self, layout: Layout) {
+        unsafe {
+            lv_cont_set_layout(self.0, layout as lv_layout_t);
+        }
+    }
+
+    /// Enable the horizontal or vertical fit, adapting the size of the
+    /// container to its children.
+    #[inline]
+    pub fn set_fit(&mut self, hor: bool, ver: bool) {
+        unsafe {
+            lv_cont_set_fit(self.0, hor, ver);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the layout of the container.
+    #[inline]
+    pub fn get_layout(&self) -> Layout {
+        Layout::from_lv_layout(unsafe { lv_cont_get_layout(self.0) })
+    }
+
+    /// Get whether the horizontal fit is enabled.
+    #[inline]
+    pub fn get_hor_fit(&self) -> bool {
+        unsafe { lv_cont_get_hor_fit(self.0) }
+    }
+
+    /// Get whether the vertical fit is enabled.
+    #[inline]
+    pub fn get_ver_fit(&self) -> bool {
+        unsafe { lv_cont_get_ver_fit(self.0) }
+    }
+}
+
+impl ObjT for Container {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Container(obj)
+    }
+}
+
+impl Drop for Container {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::options;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DropDownStyle {
+    Background = LV_DDLIST_STYLE_BG as lv_ddlist_style_t,
+    Selected = LV_DDLIST_STYLE_SEL as lv_ddlist_style_t,
+    Scrollbar = LV_DDLIST_STYLE_SB as lv_ddlist_style_t,
+}
+
+/// A drop down list, sending `Event::ValueChanged` to its event handler when
+/// the user has selected an option.
+#[repr(transparent)]
+pub struct DropDown(*mut lv_obj_t);
+
+impl DropDown {
+    /// Create a new drop down list on `parent`, deleted when the returned
+    /// handle is dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new drop down list as a child of `parent`, optionally copying
+    /// another drop down list.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&DropDown>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_ddlist_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(ddlist: *mut lv_obj_t) -> Option<Self> {
+        if ddlist.is_null() {
+            None
+        } else {
+            lv_ddlist_set_action(ddlist, Some(event::value_changed_action));
+            Some(DropDown(ddlist))
+        }
+    }
+
+    /// Open the drop down list, showing the selected option.
+    #[inline]
+    pub fn open(&mut self, anim: bool) {
+        unsafe {
+            lv_ddlist_open(self.0, anim);
+        }
+    }
+
+    /// Close the drop down list.
+    #[inline]
+    pub fn close(&mut self, anim: bool) {
+        unsafe {
+            lv_ddlist_close(self.0, anim);
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the options, given without new lines and terminating nul. They
+    /// are copied by the drop down list.
+    #[inline]
+    pub fn set_options(&mut self, options: &[&[u8]]) {
+        unsafe {
+            options::with_options(options, |options| lv_ddlist_set_options(self.0, options));
+        }
+    }
+
+    /// Set the selected option.
+    #[inline]
+    pub fn set_selected(&mut self, index: u16) {
+        unsafe {
+            lv_ddlist_set_selected(self.0, index);
+        }
+    }
+
+    /// Set a fixed height for the open drop down list, 0 means the height of
+    /// all options.
+    #[inline]
+    pub fn set_fix_height(&mut self, height: lv_coord_t) {
+        unsafe {
+            lv_ddlist_set_fix_height(self.0, height);
+        }
+    }
+
+    /// Fit the width of the drop down list to the widest option.
+    #[inline]
+    pub fn set_hor_fit(&mut self, enabled: bool) {
+        unsafe {
+            lv_ddlist_set_hor_fit(self.0, enabled);
+        }
+    }
+
+    /// Set the open and close animation time.
+    #[inline]
+    pub fn set_anim_time(&mut self, anim_time: u16) {
+        unsafe {
+            lv_ddlist_set_anim_time(self.0, anim_time);
+        }
+    }
+
+    /// Set the style of a part of the drop down list.
+    #[inline]
+    pub fn set_part_style(&mut self, part: DropDownStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_ddlist_set_style(self.0, part as lv_ddlist_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the index of the selected option.
+    #[inline]
+    pub fn get_selected(&self) -> u16 {
+        unsafe { lv_ddlist_get_selected(self.0) }
+    }
+
+    /// Get the fixed height of the open drop down list.
+    #[inline]
+    pub fn get_fix_height(&self) -> lv_coord_t {
+        unsafe { lv_ddlist_get_fix_height(self.0) }
+    }
+
+    /// Get the open and close animation time.
+    #[inline]
+    pub fn get_anim_time(&self) -> u16 {
+        unsafe { lv_ddlist_get_anim_time(self.0) }
+    }
+}
+
+impl ObjT for DropDown {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        DropDown(obj)
+    }
+}
+
+impl Drop for DropDown {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -1,39 +1,120 @@
+use crate::callback;
 use crate::color::Color;
-use crate::obj::ObjT;
+use crate::obj::{Child, ObjT, Owned};
 
+use core::fmt;
+use core::mem;
 use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use ffi::*;
 use lvgl_sys::*;
 
+/// The design function of the gauges, recorded by `set_label_formatter`
+/// before replacing it and called by `format_design`. All the gauges share
+/// it, so the first one recorded is kept.
+static GAUGE_DESIGN: AtomicUsize = AtomicUsize::new(0);
+
+/// The colors of the needles of a gauge: a static array with the color of
+/// each needle, up to 255 needles.
+pub trait NeedleColors {
+    fn as_colors(self) -> &'static [Color];
+}
+
+macro_rules! impl_needle_colors {
+    ($($count:tt)+) => {
+        $(
+            impl NeedleColors for &'static [Color; $count] {
+                #[inline]
+                fn as_colors(self) -> &'static [Color] {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_needle_colors! {
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34
+    35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64 65 66
+    67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96 97 98
+    99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119 120 121 122
+    123 124 125 126 127 128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143 144 145 146
+    147 148 149 150 151 152 153 154 155 156 157 158 159 160 161 162 163 164 165 166 167 168 169 170
+    171 172 173 174 175 176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191 192 193 194
+    195 196 197 198 199 200 201 202 203 204 205 206 207 208 209 210 211 212 213 214 215 216 217 218
+    219 220 221 222 223 224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239 240 241 242
+    243 244 245 246 247 248 249 250 251 252 253 254 255
+}
+
+/// The text of a scale label, written by a gauge label formatter.
+pub struct LabelBuffer {
+    buf: [u8; 16],
+    len: usize,
+}
+
+impl LabelBuffer {
+    /// Get the text written so far.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl fmt::Write for LabelBuffer {
+    /// Append a string, failing if it does not fit with the terminating nul.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end >= self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
 #[repr(transparent)]
 pub struct Gauge(*mut lv_obj_t);
 
 impl Gauge {
+    /// Create a new gauge on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new gauge as a child of `parent`, optionally copying another
+    /// gauge.
     #[inline]
-    pub fn new() -> Option<Self> {
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Gauge>) -> Option<Child<'a, Self>> {
         unsafe {
-            let gauge = lv_gauge_create(lv_scr_act(), ptr::null());
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let gauge = lv_gauge_create(parent.as_lv_obj(), copy);
             if gauge.is_null() {
                 None
             } else {
-                Some(Gauge(gauge))
+                Some(Child::new(Gauge(gauge)))
             }
         }
     }
 
     // Setter methods.
 
-    /// Set the number of needles.
-    pub fn set_needle_count(&mut self, needle_cnt: u8, colors: &'static [Color]) {
-        assert!(
-            colors.len() == needle_cnt as usize,
-            "Unexpected number of needle colors"
-        );
+    /// Set the needles of the gauge, one for each color of a static array,
+    /// e.g. `gauge.set_needle_count(&NEEDLE_COLORS)`. The colors are not
+    /// copied.
+    #[inline]
+    pub fn set_needle_count(&mut self, colors: impl NeedleColors) {
+        let colors = colors.as_colors();
+        assert!(colors.len() <= u8::max_value() as usize, "Too many needles");
         unsafe {
-            lv_gauge_set_needle_count(self.0, needle_cnt, colors.as_ptr() as *const lv_color_t)
+            let colors_ptr = colors.as_ptr() as *const lv_color_t;
+            lv_gauge_set_needle_count(self.0, colors.len() as u8, colors_ptr);
         }
     }
 
-    /// Set the value of a needle.   
+    /// Set the value of a needle.
     #[inline]
     pub fn set_value(&mut self, needle_id: u8, value: i16) {
         unsafe {
@@ -60,13 +141,124 @@ impl Gauge {
         }
     }
 
-    /// Seet the scale settings.
+    /// Set the scale settings.
     #[inline]
     pub fn set_scale(&mut self, angle: u16, line_count: u8, label_count: u8) {
         unsafe {
             lv_gauge_set_scale(self.0, angle, line_count, label_count);
         }
     }
+
+    /// Set a function writing the text of the scale labels, e.g. "30s"
+    /// instead of "30". The closure must not capture any variables.
+    ///
+    /// lvgl only prints the plain numbers, so the labels are drawn by a
+    /// replaced design function instead.
+    pub fn set_label_formatter<F>(&mut self, formatter: F)
+    where
+        F: Fn(&mut LabelBuffer, i16) + Copy + 'static,
+    {
+        callback::assert_zero_sized(&formatter);
+        unsafe {
+            if gauge_design().is_none() {
+                let design = lv_obj_get_design_func(self.0);
+                let design: usize = mem::transmute(design);
+                let _ =
+                    GAUGE_DESIGN.compare_exchange(0, design, Ordering::AcqRel, Ordering::Acquire);
+            }
+            lv_obj_set_design_func(self.0, Some(format_design::<F>));
+            lv_obj_invalidate(self.0);
+        }
+    }
+
+    /// Draw the plain numbers on the scale labels again.
+    pub fn clear_label_formatter(&mut self) {
+        unsafe {
+            let design = gauge_design();
+            if design.is_some() {
+                lv_obj_set_design_func(self.0, design);
+                lv_obj_invalidate(self.0);
+            }
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the value of a needle.
+    #[inline]
+    pub fn get_value(&self, needle_id: u8) -> i16 {
+        unsafe { lv_gauge_get_value(self.0, needle_id) }
+    }
+
+    /// Get the number of needles.
+    #[inline]
+    pub fn get_needle_count(&self) -> u8 {
+        unsafe { lv_gauge_get_needle_count(self.0) }
+    }
+
+    /// Get the color of a needle.
+    #[inline]
+    pub fn get_needle_color(&self, needle_id: u8) -> Option<Color> {
+        unsafe {
+            let ext = lv_obj_get_ext_attr(self.0) as *const lv_gauge_ext_t;
+            if needle_id < (*ext).needle_count && !(*ext).needle_colors.is_null() {
+                Some((*(*ext).needle_colors.add(needle_id as usize)).into())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Get the minimum value of the gauge.
+    #[inline]
+    pub fn get_min_value(&self) -> i16 {
+        unsafe {
+            // NOTE: call the lmeter function directly.
+            lv_lmeter_get_min_value(self.0)
+        }
+    }
+
+    /// Get the maximum value of the gauge.
+    #[inline]
+    pub fn get_max_value(&self) -> i16 {
+        unsafe {
+            // NOTE: call the lmeter function directly.
+            lv_lmeter_get_max_value(self.0)
+        }
+    }
+
+    /// Get the critical value of the scale.
+    #[inline]
+    pub fn get_critical_value(&self) -> i16 {
+        unsafe {
+            // NOTE: call the lmeter function directly.
+            lv_lmeter_get_value(self.0)
+        }
+    }
+
+    /// Get the number of scale lines.
+    #[inline]
+    pub fn get_line_count(&self) -> u8 {
+        unsafe {
+            // NOTE: call the lmeter function directly.
+            lv_lmeter_get_line_count(self.0)
+        }
+    }
+
+    /// Get the number of scale labels.
+    #[inline]
+    pub fn get_label_count(&self) -> u8 {
+        unsafe { lv_gauge_get_label_count(self.0) }
+    }
+
+    /// Get the angle of the scale.
+    #[inline]
+    pub fn get_scale_angle(&self) -> u16 {
+        unsafe {
+            // NOTE: call the lmeter function directly.
+            lv_lmeter_get_scale_angle(self.0)
+        }
+    }
 }
 
 impl ObjT for Gauge {
@@ -74,4 +266,140 @@ impl ObjT for Gauge {
     unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
         self.0
     }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Gauge(obj)
+    }
+}
+
+impl Drop for Gauge {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+/// Design function of the gauges with a label formatter. The original design
+/// function draws everything but the labels, which are placed the same way.
+unsafe extern "C" fn format_design<F>(
+    gauge: *mut lv_obj_t,
+    mask: *const lv_area_t,
+    mode: lv_design_mode_t,
+) -> bool
+where
+    F: Fn(&mut LabelBuffer, i16) + Copy,
+{
+    let design = match gauge_design() {
+        Some(design) => design,
+        None => return true,
+    };
+    if mode != LV_DESIGN_DRAW_MAIN as lv_design_mode_t {
+        return design(gauge, mask, mode);
+    }
+
+    // Hide the labels from the original design function.
+    let ext = lv_obj_get_ext_attr(gauge) as *mut lv_gauge_ext_t;
+    let label_count = (*ext).label_count;
+    let res = {
+        let _hidden = HiddenLabels::new(ext);
+        design(gauge, mask, mode)
+    };
+    if label_count < 2 {
+        return res;
+    }
+
+    let formatter: F = callback::recreate();
+    let style = lv_obj_get_style(gauge);
+    let opa_scale = lv_obj_get_opa_scale(gauge);
+    let mut coords: lv_area_t = core::mem::zeroed();
+    lv_obj_get_coords(gauge, &mut coords);
+
+    let width = lv_obj_get_width(gauge);
+    let height = lv_obj_get_height(gauge);
+    let r = i32::from(width / 2 - 3 * (*style).body.padding.hor - (*style).body.padding.inner);
+    let x_ofs = i32::from(width / 2 + coords.x1);
+    let y_ofs = i32::from(height / 2 + coords.y1);
+    let scale_angle = i32::from(lv_lmeter_get_scale_angle(gauge));
+    let angle_ofs = 90 + (360 - scale_angle) / 2;
+    let min = i32::from(lv_lmeter_get_min_value(gauge));
+    let max = i32::from(lv_lmeter_get_max_value(gauge));
+    let last = i32::from(label_count) - 1;
+
+    for i in 0..=last {
+        let angle = (i * scale_angle) / last + angle_ofs;
+        let y = i32::from(lv_trigo_sin(angle as i16)) * r / LV_TRIGO_SIN_MAX as i32 + y_ofs;
+        let x = i32::from(lv_trigo_sin((angle + 90) as i16)) * r / LV_TRIGO_SIN_MAX as i32 + x_ofs;
+        let value = (max - min) * i / last + min;
+
+        let mut label = LabelBuffer {
+            buf: [0; 16],
+            len: 0,
+        };
+        formatter(&mut label, value as i16);
+        // The buffer always keeps room for the terminating nul.
+        label.buf[label.len] = 0;
+        let text = label.buf.as_ptr() as *const c_char;
+
+        let mut size = lv_point_t { x: 0, y: 0 };
+        lv_txt_get_size(
+            &mut size,
+            text,
+            (*style).text.font,
+            (*style).text.letter_space,
+            (*style).text.line_space,
+            LV_COORD_MAX as lv_coord_t,
+            LV_TXT_FLAG_NONE as lv_txt_flag_t,
+        );
+        let x1 = x as lv_coord_t - size.x / 2;
+        let y1 = y as lv_coord_t - size.y / 2;
+        let label_coords = lv_area_t {
+            x1,
+            y1,
+            x2: x1 + size.x,
+            y2: y1 + size.y,
+        };
+        lv_draw_label(
+            &label_coords,
+            mask,
+            style,
+            opa_scale,
+            text,
+            LV_TXT_FLAG_NONE as lv_txt_flag_t,
+            ptr::null_mut(),
+        );
+    }
+    res
+}
+
+/// Get the design function recorded in `GAUGE_DESIGN`.
+#[inline]
+fn gauge_design() -> lv_design_func_t {
+    unsafe { mem::transmute(GAUGE_DESIGN.load(Ordering::Acquire)) }
+}
+
+/// Sets the label count of a gauge to 0, and restores it when dropped.
+struct HiddenLabels {
+    ext: *mut lv_gauge_ext_t,
+    label_count: u8,
+}
+
+impl HiddenLabels {
+    #[inline]
+    unsafe fn new(ext: *mut lv_gauge_ext_t) -> Self {
+        let label_count = (*ext).label_count;
+        (*ext).label_count = 0;
+        HiddenLabels { ext, label_count }
+    }
+}
+
+impl Drop for HiddenLabels {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            (*self.ext).label_count = self.label_count;
+        }
+    }
 }
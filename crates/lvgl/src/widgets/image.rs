@@ -1,4 +1,4 @@
-use crate::obj::ObjT;
+use crate::obj::{Child, ObjT, Owned};
 
 use core::ffi::c_void;
 use core::ptr;
@@ -8,14 +8,24 @@ use lvgl_sys::*;
 pub struct Image(*mut lv_obj_t);
 
 impl Image {
+    /// Create a new image on `parent`, deleted when the returned handle is
+    /// dropped.
     #[inline]
-    pub fn new() -> Option<Self> {
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new image as a child of `parent`, optionally copying another
+    /// image.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Image>) -> Option<Child<'a, Self>> {
         unsafe {
-            let image = lv_img_create(lv_scr_act(), ptr::null());
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let image = lv_img_create(parent.as_lv_obj(), copy);
             if image.is_null() {
                 None
             } else {
-                Some(Image(image))
+                Some(Child::new(Image(image)))
             }
         }
     }
@@ -44,6 +54,20 @@ impl ObjT for Image {
     unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
         self.0
     }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Image(obj)
+    }
+}
+
+impl Drop for Image {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
 }
 
 pub trait ImageSource {
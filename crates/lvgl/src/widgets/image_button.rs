@@ -0,0 +1,123 @@
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::{ButtonState, ButtonStyle, ImageSource};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(transparent)]
+pub struct ImageButton(*mut lv_obj_t);
+
+impl ImageButton {
+    /// Create a new image button on `parent`, deleted when the returned handle
+    /// is dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new image button as a child of `parent`, optionally copying
+    /// another image button.
+    #[inline]
+    pub fn create<'a>(
+        parent: &'a impl ObjT,
+        copy: Option<&ImageButton>,
+    ) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let imgbtn = lv_imgbtn_create(parent.as_lv_obj(), copy);
+            if imgbtn.is_null() {
+                None
+            } else {
+                Some(Child::new(ImageButton(imgbtn)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the image displayed in a state.
+    #[inline]
+    pub fn set_src(&mut self, state: ButtonState, src: &'static dyn ImageSource) {
+        unsafe {
+            lv_imgbtn_set_src(self.0, state as lv_btn_state_t, src.as_img_src_ptr());
+        }
+    }
+
+    /// Enable the toggled states.
+    #[inline]
+    pub fn set_toggle(&mut self, enabled: bool) {
+        unsafe {
+            // NOTE: call the btn function directly.
+            lv_btn_set_toggle(self.0, enabled);
+        }
+    }
+
+    /// Set the state of the image button.
+    #[inline]
+    pub fn set_state(&mut self, state: ButtonState) {
+        unsafe {
+            // NOTE: call the btn function directly.
+            lv_btn_set_state(self.0, state as lv_btn_state_t);
+        }
+    }
+
+    /// Toggle the state of the image button (ON->OFF, OFF->ON).
+    #[inline]
+    pub fn toggle(&mut self) {
+        unsafe {
+            // NOTE: call the btn function directly.
+            lv_btn_toggle(self.0);
+        }
+    }
+
+    /// Set the style of a part of the image button.
+    #[inline]
+    pub fn set_part_style(&mut self, part: ButtonStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_imgbtn_set_style(self.0, part as lv_imgbtn_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the current state of the image button.
+    #[inline]
+    pub fn get_state(&self) -> ButtonState {
+        unsafe {
+            // NOTE: call the btn function directly.
+            ButtonState::from_lv_state(lv_btn_get_state(self.0))
+        }
+    }
+
+    /// Get the toggle enable attribute of the image button.
+    #[inline]
+    pub fn get_toggle(&self) -> bool {
+        unsafe {
+            // NOTE: call the btn function directly.
+            lv_btn_get_toggle(self.0)
+        }
+    }
+}
+
+impl ObjT for ImageButton {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        ImageButton(obj)
+    }
+}
+
+impl Drop for ImageButton {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::TextArea;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyboardMode {
+    Text = LV_KB_MODE_TEXT as lv_kb_mode_t,
+    Number = LV_KB_MODE_NUM as lv_kb_mode_t,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyboardStyle {
+    Background = LV_KB_STYLE_BG as lv_kb_style_t,
+    ButtonReleased = LV_KB_STYLE_BTN_REL as lv_kb_style_t,
+    ButtonPressed = LV_KB_STYLE_BTN_PR as lv_kb_style_t,
+    ButtonToggledReleased = LV_KB_STYLE_BTN_TGL_REL as lv_kb_style_t,
+    ButtonToggledPressed = LV_KB_STYLE_BTN_TGL_PR as lv_kb_style_t,
+    ButtonInactive = LV_KB_STYLE_BTN_INA as lv_kb_style_t,
+}
+
+/// An on-screen keyboard writing into a bound text area. The ok and hide keys
+/// send `Event::Apply` and `Event::Cancel` to its event handler.
+///
+/// Unlike the plain lvgl keyboard, it is never deleted by these keys.
+#[repr(transparent)]
+pub struct Keyboard(*mut lv_obj_t);
+
+impl Keyboard {
+    /// Create a new keyboard on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new keyboard as a child of `parent`, optionally copying
+    /// another keyboard.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Keyboard>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_kb_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(kb: *mut lv_obj_t) -> Option<Self> {
+        if kb.is_null() {
+            None
+        } else {
+            // Without actions the keyboard deletes itself on ok and hide.
+            lv_kb_set_ok_action(kb, Some(event::apply_action));
+            lv_kb_set_hide_action(kb, Some(event::cancel_action));
+            Some(Keyboard(kb))
+        }
+    }
+
+    // Setter methods.
+
+    /// Bind a text area to the keyboard, the keys will write into it.
+    ///
+    /// # Safety
+    ///
+    /// The text area must not be deleted while it is bound: it has to be
+    /// unbound with `clear_text_area` first, or outlive the keyboard.
+    #[inline]
+    pub unsafe fn set_text_area(&mut self, ta: &TextArea) {
+        lv_kb_set_ta(self.0, ta.as_lv_obj());
+    }
+
+    /// Unbind the text area of the keyboard.
+    #[inline]
+    pub fn clear_text_area(&mut self) {
+        unsafe {
+            lv_kb_set_ta(self.0, ptr::null_mut());
+        }
+    }
+
+    /// Set the mode of the keyboard.
+    #[inline]
+    pub fn set_mode(&mut self, mode: KeyboardMode) {
+        unsafe {
+            lv_kb_set_mode(self.0, mode as lv_kb_mode_t);
+        }
+    }
+
+    /// Show the cursor of the bound text area only while it is bound.
+    #[inline]
+    pub fn set_cursor_manage(&mut self, enabled: bool) {
+        unsafe {
+            lv_kb_set_cursor_manage(self.0, enabled);
+        }
+    }
+
+    /// Set the style of a part of the keyboard.
+    #[inline]
+    pub fn set_part_style(&mut self, part: KeyboardStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_kb_set_style(self.0, part as lv_kb_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Check whether a text area is bound to the keyboard.
+    #[inline]
+    pub fn has_text_area(&self) -> bool {
+        unsafe { !lv_kb_get_ta(self.0).is_null() }
+    }
+
+    /// Get the mode of the keyboard.
+    #[inline]
+    pub fn get_mode(&self) -> KeyboardMode {
+        let mode = unsafe { lv_kb_get_mode(self.0) };
+        if mode == LV_KB_MODE_NUM as lv_kb_mode_t {
+            KeyboardMode::Number
+        } else {
+            KeyboardMode::Text
+        }
+    }
+
+    /// Get whether the keyboard manages the cursor of the text area.
+    #[inline]
+    pub fn get_cursor_manage(&self) -> bool {
+        unsafe { lv_kb_get_cursor_manage(self.0) }
+    }
+}
+
+impl ObjT for Keyboard {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Keyboard(obj)
+    }
+}
+
+impl Drop for Keyboard {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
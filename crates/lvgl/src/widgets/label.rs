@@ -1,4 +1,4 @@
-use crate::obj::ObjT;
+use crate::obj::{Child, ObjT, Owned};
 
 use core::ptr;
 use ffi::*;
@@ -27,15 +27,24 @@ pub enum LabelAlign {
 pub struct Label(*mut lv_obj_t);
 
 impl Label {
-    /// Create a new label.
+    /// Create a new label on `parent`, deleted when the returned handle is
+    /// dropped.
     #[inline]
-    pub fn new() -> Option<Self> {
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new label as a child of `parent`, optionally copying another
+    /// label.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Label>) -> Option<Child<'a, Self>> {
         unsafe {
-            let label = lv_label_create(lv_scr_act(), ptr::null());
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let label = lv_label_create(parent.as_lv_obj(), copy);
             if label.is_null() {
                 None
             } else {
-                Some(Label(label))
+                Some(Child::new(Label(label)))
             }
         }
     }
@@ -110,4 +119,18 @@ impl ObjT for Label {
     unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
         self.0
     }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Label(obj)
+    }
+}
+
+impl Drop for Label {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
 }
@@ -0,0 +1,94 @@
+use crate::obj::{Child, ObjT, Owned};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(transparent)]
+pub struct Led(*mut lv_obj_t);
+
+impl Led {
+    /// Create a new LED on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new LED as a child of `parent`, optionally copying another
+    /// LED.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Led>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let led = lv_led_create(parent.as_lv_obj(), copy);
+            if led.is_null() {
+                None
+            } else {
+                Some(Child::new(Led(led)))
+            }
+        }
+    }
+
+    /// Light on the LED.
+    #[inline]
+    pub fn on(&mut self) {
+        unsafe {
+            lv_led_on(self.0);
+        }
+    }
+
+    /// Light off the LED.
+    #[inline]
+    pub fn off(&mut self) {
+        unsafe {
+            lv_led_off(self.0);
+        }
+    }
+
+    /// Toggle the state of the LED.
+    #[inline]
+    pub fn toggle(&mut self) {
+        unsafe {
+            lv_led_toggle(self.0);
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the brightness of the LED, from 0 (darkest) to 255 (brightest).
+    #[inline]
+    pub fn set_bright(&mut self, bright: u8) {
+        unsafe {
+            lv_led_set_bright(self.0, bright);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the brightness of the LED.
+    #[inline]
+    pub fn get_bright(&self) -> u8 {
+        unsafe { lv_led_get_bright(self.0) }
+    }
+}
+
+impl ObjT for Led {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Led(obj)
+    }
+}
+
+impl Drop for Led {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
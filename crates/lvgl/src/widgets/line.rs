@@ -0,0 +1,97 @@
+use crate::display::Point;
+use crate::obj::{Child, ObjT, Owned};
+
+use core::ptr;
+use lvgl_sys::*;
+
+/// A polyline connecting a list of points.
+#[repr(transparent)]
+pub struct Line(*mut lv_obj_t);
+
+impl Line {
+    /// Create a new line on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new line as a child of `parent`, optionally copying another
+    /// line.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Line>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let line = lv_line_create(parent.as_lv_obj(), copy);
+            if line.is_null() {
+                None
+            } else {
+                Some(Child::new(Line(line)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the points of the line, relative to the object. The points are
+    /// not copied, so they have to be static.
+    #[inline]
+    pub fn set_points(&mut self, points: &'static [Point]) {
+        unsafe {
+            let points_ptr = points.as_ptr() as *const lv_point_t;
+            lv_line_set_points(self.0, points_ptr, points.len() as u16);
+        }
+    }
+
+    /// Enable or disable the automatic sizing of the object to its points.
+    #[inline]
+    pub fn set_auto_size(&mut self, enabled: bool) {
+        unsafe {
+            lv_line_set_auto_size(self.0, enabled);
+        }
+    }
+
+    /// Invert the y coordinates, making them grow upwards from the bottom of
+    /// the object.
+    #[inline]
+    pub fn set_y_invert(&mut self, enabled: bool) {
+        unsafe {
+            lv_line_set_y_invert(self.0, enabled);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get whether the automatic sizing is enabled.
+    #[inline]
+    pub fn get_auto_size(&self) -> bool {
+        unsafe { lv_line_get_auto_size(self.0) }
+    }
+
+    /// Get whether the y coordinates are inverted.
+    #[inline]
+    pub fn get_y_invert(&self) -> bool {
+        unsafe { lv_line_get_y_invert(self.0) }
+    }
+}
+
+impl ObjT for Line {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Line(obj)
+    }
+}
+
+impl Drop for Line {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::obj::ObjT;
+use crate::obj::{Child, ObjT, Owned};
 
 use core::ptr;
 use lvgl_sys::*;
@@ -7,14 +7,24 @@ use lvgl_sys::*;
 pub struct LineMeter(*mut lv_obj_t);
 
 impl LineMeter {
+    /// Create a new line meter on `parent`, deleted when the returned handle is
+    /// dropped.
     #[inline]
-    pub fn new() -> Option<Self> {
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new line meter as a child of `parent`, optionally copying another
+    /// line meter.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&LineMeter>) -> Option<Child<'a, Self>> {
         unsafe {
-            let lmeter = lv_lmeter_create(lv_scr_act(), ptr::null());
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let lmeter = lv_lmeter_create(parent.as_lv_obj(), copy);
             if lmeter.is_null() {
                 None
             } else {
-                Some(LineMeter(lmeter))
+                Some(Child::new(LineMeter(lmeter)))
             }
         }
     }
@@ -47,7 +57,35 @@ impl LineMeter {
 
     // Getter methods.
 
-    // Get the value of the line meter.
+    /// Get the value of the line meter.
+    #[inline]
+    pub fn get_value(&self) -> i16 {
+        unsafe { lv_lmeter_get_value(self.0) }
+    }
+
+    /// Get the minimum value of the line meter.
+    #[inline]
+    pub fn get_min_value(&self) -> i16 {
+        unsafe { lv_lmeter_get_min_value(self.0) }
+    }
+
+    /// Get the maximum value of the line meter.
+    #[inline]
+    pub fn get_max_value(&self) -> i16 {
+        unsafe { lv_lmeter_get_max_value(self.0) }
+    }
+
+    /// Get the number of scale lines.
+    #[inline]
+    pub fn get_line_count(&self) -> u8 {
+        unsafe { lv_lmeter_get_line_count(self.0) }
+    }
+
+    /// Get the angle of the scale.
+    #[inline]
+    pub fn get_scale_angle(&self) -> u16 {
+        unsafe { lv_lmeter_get_scale_angle(self.0) }
+    }
 }
 
 impl ObjT for LineMeter {
@@ -55,4 +93,18 @@ impl ObjT for LineMeter {
     unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
         self.0
     }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        LineMeter(obj)
+    }
+}
+
+impl Drop for LineMeter {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
 }
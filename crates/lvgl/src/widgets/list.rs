@@ -0,0 +1,222 @@
+use crate::event::{self, Event};
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::{button, options};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListStyle {
+    Background = LV_LIST_STYLE_BG as lv_list_style_t,
+    Scrollable = LV_LIST_STYLE_SCRL as lv_list_style_t,
+    Scrollbar = LV_LIST_STYLE_SB as lv_list_style_t,
+    EdgeFlash = LV_LIST_STYLE_EDGE_FLASH as lv_list_style_t,
+    ButtonReleased = LV_LIST_STYLE_BTN_REL as lv_list_style_t,
+    ButtonPressed = LV_LIST_STYLE_BTN_PR as lv_list_style_t,
+    ButtonToggledReleased = LV_LIST_STYLE_BTN_TGL_REL as lv_list_style_t,
+    ButtonToggledPressed = LV_LIST_STYLE_BTN_TGL_PR as lv_list_style_t,
+    ButtonInactive = LV_LIST_STYLE_BTN_INA as lv_list_style_t,
+}
+
+/// A scrollable list of options, sending `Event::ValueChanged` to its event
+/// handler when the user has selected an option.
+///
+/// The selected option is shown in the toggled button state.
+#[repr(transparent)]
+pub struct List(*mut lv_obj_t);
+
+impl List {
+    /// Create a new list on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new list as a child of `parent`, optionally copying another
+    /// list.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&List>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let list = lv_list_create(parent.as_lv_obj(), copy);
+            if list.is_null() {
+                None
+            } else {
+                Some(Child::new(List(list)))
+            }
+        }
+    }
+
+    /// Append an option to the list, given without terminating nul.
+    #[inline]
+    pub fn add_option(&mut self, option: &[u8]) {
+        unsafe {
+            let btn = options::with_options(&[option], |option| {
+                lv_list_add(self.0, ptr::null(), option, Some(select_action))
+            });
+            assert!(!btn.is_null(), "Out of lvgl memory");
+        }
+    }
+
+    /// Move the list one option up.
+    #[inline]
+    pub fn up(&mut self) {
+        unsafe {
+            lv_list_up(self.0);
+        }
+    }
+
+    /// Move the list one option down.
+    #[inline]
+    pub fn down(&mut self) {
+        unsafe {
+            lv_list_down(self.0);
+        }
+    }
+
+    /// Scroll the list to the selected option, if there is one.
+    #[inline]
+    pub fn focus_selected(&mut self, anim: bool) {
+        unsafe {
+            if let Some(btn) = self.find_btn(|btn, _| button::is_toggled(btn)) {
+                lv_list_focus(btn, anim);
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Replace all options of the list, given without terminating nul.
+    pub fn set_options(&mut self, options: &[&[u8]]) {
+        unsafe {
+            lv_list_clean(self.0);
+        }
+        for option in options {
+            self.add_option(option);
+        }
+    }
+
+    /// Select an option, or clear the selection with `None`.
+    pub fn set_selected(&mut self, index: Option<u16>) {
+        unsafe {
+            self.find_btn(|btn, i| {
+                set_toggled(btn, Some(i) == index);
+                false
+            });
+        }
+    }
+
+    /// Set the scroll animation time.
+    #[inline]
+    pub fn set_anim_time(&mut self, anim_time: u16) {
+        unsafe {
+            lv_list_set_anim_time(self.0, anim_time);
+        }
+    }
+
+    /// Set the style of a part of the list.
+    #[inline]
+    pub fn set_part_style(&mut self, part: ListStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_list_set_style(self.0, part as lv_list_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the index of the selected option.
+    pub fn get_selected(&self) -> Option<u16> {
+        let mut selected = None;
+        unsafe {
+            self.find_btn(|btn, i| {
+                if button::is_toggled(btn) {
+                    selected = Some(i);
+                }
+                selected.is_some()
+            });
+        }
+        selected
+    }
+
+    /// Get the number of options.
+    pub fn count_options(&self) -> u16 {
+        let mut count = 0;
+        unsafe {
+            self.find_btn(|_, _| {
+                count += 1;
+                false
+            });
+        }
+        count
+    }
+
+    /// Get the scroll animation time.
+    #[inline]
+    pub fn get_anim_time(&self) -> u16 {
+        unsafe { lv_list_get_anim_time(self.0) }
+    }
+
+    unsafe fn find_btn(
+        &self,
+        mut f: impl FnMut(*mut lv_obj_t, u16) -> bool,
+    ) -> Option<*mut lv_obj_t> {
+        // The buttons are returned in the order they have been added.
+        let mut btn = lv_list_get_next_btn(self.0, ptr::null_mut());
+        let mut i = 0;
+        while !btn.is_null() {
+            if f(btn, i) {
+                return Some(btn);
+            }
+            btn = lv_list_get_next_btn(self.0, btn);
+            i += 1;
+        }
+        None
+    }
+}
+
+impl ObjT for List {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        List(obj)
+    }
+}
+
+impl Drop for List {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+unsafe fn set_toggled(btn: *mut lv_obj_t, toggled: bool) {
+    let state = if toggled {
+        LV_BTN_STATE_TGL_REL
+    } else {
+        LV_BTN_STATE_REL
+    };
+    lv_btn_set_state(btn, state as lv_btn_state_t);
+}
+
+/// Release action of the list buttons, selecting the button and delivering
+/// `ValueChanged` to the event handler of the list.
+unsafe extern "C" fn select_action(btn: *mut lv_obj_t) -> lv_res_t {
+    // The buttons live on the scrollable of the list.
+    let list = lv_obj_get_parent(lv_obj_get_parent(btn));
+    let mut other = lv_list_get_next_btn(list, ptr::null_mut());
+    while !other.is_null() {
+        set_toggled(other, other == btn);
+        other = lv_list_get_next_btn(list, other);
+    }
+    event::dispatch(list, Event::ValueChanged)
+}
@@ -0,0 +1,248 @@
+#[cfg(use_lv_animation)]
+use crate::anim::Anim;
+use crate::color::Color;
+use crate::event::{self, Event};
+use crate::lazy::Lazy;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::button_matrix::{self, ButtonMapEntry};
+use crate::widgets::options;
+
+use core::ptr;
+use ffi::*;
+use lvgl_sys::*;
+
+/// The style of the `Modal` backdrops.
+static BACKDROP_STYLE: Lazy<Style> = Lazy::new(|| {
+    Style::build(|style| {
+        style
+            .copy_from(Style::plain())
+            .body_color(Color::BLACK)
+            .body_opa(LV_OPA_50 as lv_opa_t)
+    })
+});
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MessageBoxStyle {
+    Background = LV_MBOX_STYLE_BG as lv_mbox_style_t,
+    ButtonsBackground = LV_MBOX_STYLE_BTN_BG as lv_mbox_style_t,
+    ButtonReleased = LV_MBOX_STYLE_BTN_REL as lv_mbox_style_t,
+    ButtonPressed = LV_MBOX_STYLE_BTN_PR as lv_mbox_style_t,
+    ButtonToggledReleased = LV_MBOX_STYLE_BTN_TGL_REL as lv_mbox_style_t,
+    ButtonToggledPressed = LV_MBOX_STYLE_BTN_TGL_PR as lv_mbox_style_t,
+    ButtonInactive = LV_MBOX_STYLE_BTN_INA as lv_mbox_style_t,
+}
+
+/// A message box with a text and a row of buttons, sending
+/// `Event::ValueChanged` to its event handler when a button is released.
+///
+/// Unlike the plain lvgl message box, it never deletes itself: it is only
+/// deleted when dropped.
+#[repr(transparent)]
+pub struct MessageBox(*mut lv_obj_t);
+
+impl MessageBox {
+    /// Create a new message box on `parent`, deleted when the returned handle
+    /// is dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new message box as a child of `parent`, optionally copying
+    /// another message box.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&MessageBox>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let mbox = lv_mbox_create(parent.as_lv_obj(), copy);
+            if mbox.is_null() {
+                None
+            } else {
+                Some(Child::new(MessageBox(mbox)))
+            }
+        }
+    }
+
+    /// Add buttons to the message box. The released one is given by
+    /// `get_pressed_btn`. The map is not copied, so it has to be static.
+    #[inline]
+    pub fn add_btns(&mut self, map: &'static [ButtonMapEntry]) {
+        button_matrix::assert_terminated(map);
+        unsafe {
+            let map = map.as_ptr() as *mut *const c_char;
+            lv_mbox_add_btns(self.0, map, Some(btn_action));
+        }
+    }
+
+    /// Hide the message box after `delay` ms, up to 32767, and send
+    /// `Event::Cancel` to its event handler, unless `stop_auto_close` is
+    /// called before. The `Modal` backdrop of the message box, if any, is
+    /// hidden with it.
+    #[cfg(use_lv_animation)]
+    #[inline]
+    pub fn start_auto_close(&mut self, delay: u16) {
+        // Not `lv_mbox_start_auto_close`, which would delete the message box.
+        Anim::new(self)
+            .time(0)
+            .delay(delay)
+            .on_end(|mbox: &mut MessageBox| unsafe {
+                let parent = lv_obj_get_parent(mbox.0);
+                if Modal::is_backdrop(parent) {
+                    lv_obj_set_hidden(parent, true);
+                } else {
+                    mbox.set_hidden(true);
+                }
+                event::dispatch(mbox.0, Event::Cancel);
+            })
+            .start();
+    }
+
+    /// Stop the auto close timer.
+    #[cfg(use_lv_animation)]
+    #[inline]
+    pub fn stop_auto_close(&mut self) {
+        self.cancel_anims();
+    }
+
+    // Setter methods.
+
+    /// Set the text of the message box, given without terminating nul.
+    /// Memory will be allocated to store the text by the message box.
+    #[inline]
+    pub fn set_text(&mut self, text: &[u8]) {
+        unsafe {
+            options::with_options(&[text], |text| lv_mbox_set_text(self.0, text));
+        }
+    }
+
+    /// Set the animation time of the message box.
+    #[inline]
+    pub fn set_anim_time(&mut self, anim_time: u16) {
+        unsafe {
+            lv_mbox_set_anim_time(self.0, anim_time);
+        }
+    }
+
+    /// Set the style of a part of the message box.
+    #[inline]
+    pub fn set_part_style(&mut self, part: MessageBoxStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_mbox_set_style(self.0, part as lv_mbox_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the animation time of the message box.
+    #[inline]
+    pub fn get_anim_time(&self) -> u16 {
+        unsafe { lv_mbox_get_anim_time(self.0) }
+    }
+
+    /// Get the index of the last released button in the map, not counting
+    /// the line breaks.
+    #[inline]
+    pub fn get_pressed_btn(&self) -> u16 {
+        // Stored by `btn_action`.
+        unsafe { lv_obj_get_free_num(self.0) as u16 }
+    }
+}
+
+impl ObjT for MessageBox {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        MessageBox(obj)
+    }
+}
+
+impl Drop for MessageBox {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+/// A backdrop covering the screen on the top layer. It dims everything below
+/// and catches the input, so a message box created on it is modal.
+#[repr(transparent)]
+pub struct Modal(*mut lv_obj_t);
+
+impl Modal {
+    /// Create a new backdrop on the top layer.
+    pub fn new() -> Option<Self> {
+        unsafe {
+            let top = lv_layer_top();
+            let backdrop = lv_obj_create(top, ptr::null());
+            if backdrop.is_null() {
+                return None;
+            }
+            let mut modal = Modal(backdrop);
+            modal.set_style(&BACKDROP_STYLE);
+            modal.set_size(lv_obj_get_width(top), lv_obj_get_height(top));
+            Some(modal)
+        }
+    }
+
+    /// Check whether an object is a backdrop created by `new`.
+    #[cfg(use_lv_animation)]
+    #[inline]
+    unsafe fn is_backdrop(obj: *mut lv_obj_t) -> bool {
+        !obj.is_null()
+            && lv_obj_get_parent(obj) == lv_layer_top()
+            && lv_obj_get_style(obj) == BACKDROP_STYLE.as_lv_style()
+    }
+}
+
+impl ObjT for Modal {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Modal(obj)
+    }
+}
+
+impl Drop for Modal {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+/// Action of the buttons, storing the index of the released one and
+/// delivering `ValueChanged` to the event handler of the message box.
+unsafe extern "C" fn btn_action(btnm: *mut lv_obj_t, txt: *const c_char) -> lv_res_t {
+    // lvgl passes the text from the map, after its control byte if any.
+    let mut entry = lv_btnm_get_map(btnm);
+    let mut index = 0;
+    while **entry != 0 {
+        let text = *entry;
+        let ctrl = (*text as u8 as u32 & LV_BTNM_CTRL_CODE) != 0;
+        if text == txt || (ctrl && text.add(1) == txt) {
+            break;
+        }
+        if !(*text == b'\n' as c_char && *text.add(1) == 0) {
+            index += 1;
+        }
+        entry = entry.add(1);
+    }
+
+    let mbox = lv_mbox_get_from_btn(btnm);
+    lv_obj_set_free_num(mbox, index);
+    event::dispatch(mbox, Event::ValueChanged)
+}
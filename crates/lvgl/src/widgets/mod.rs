@@ -1,17 +1,119 @@
+#[cfg(use_lv_arc)]
+mod arc;
+#[cfg(use_lv_bar)]
+mod bar;
+#[cfg(use_lv_btn)]
+mod button;
+#[cfg(use_lv_btnm)]
+mod button_matrix;
+#[cfg(use_lv_calendar)]
+mod calendar;
+#[cfg(use_lv_chart)]
+mod chart;
+#[cfg(all(use_lv_cb, use_lv_btn))]
+mod checkbox;
+#[cfg(use_lv_cont)]
+mod container;
+#[cfg(use_lv_ddlist)]
+mod drop_down;
 #[cfg(use_lv_gauge)]
 mod gauge;
 #[cfg(use_lv_img)]
 mod image;
+#[cfg(all(use_lv_imgbtn, use_lv_btn, use_lv_img))]
+mod image_button;
+#[cfg(all(use_lv_kb, use_lv_ta, use_lv_label))]
+mod keyboard;
 #[cfg(use_lv_label)]
 mod label;
+#[cfg(use_lv_led)]
+mod led;
+#[cfg(use_lv_line)]
+mod line;
 #[cfg(use_lv_lmeter)]
 mod line_meter;
+#[cfg(all(use_lv_list, use_lv_btn))]
+mod list;
+#[cfg(all(use_lv_mbox, use_lv_btnm))]
+mod message_box;
+#[cfg(any(
+    use_lv_cb,
+    use_lv_ddlist,
+    use_lv_list,
+    use_lv_mbox,
+    use_lv_ta,
+    use_lv_tabview,
+    use_lv_win
+))]
+mod options;
+#[cfg(all(use_lv_page, use_lv_cont))]
+mod page;
+#[cfg(use_lv_preload)]
+mod preload;
+#[cfg(all(use_lv_roller, use_lv_ddlist))]
+mod roller;
+#[cfg(use_lv_slider)]
+mod slider;
+#[cfg(use_lv_sw)]
+mod switch;
+#[cfg(all(use_lv_tabview, use_lv_page, use_lv_cont))]
+mod tab_view;
+#[cfg(all(use_lv_ta, use_lv_label))]
+mod text_area;
+#[cfg(all(use_lv_win, use_lv_page, use_lv_cont))]
+mod window;
 
+#[cfg(use_lv_arc)]
+pub use self::arc::*;
+#[cfg(use_lv_bar)]
+pub use self::bar::*;
+#[cfg(use_lv_btn)]
+pub use self::button::*;
+#[cfg(use_lv_btnm)]
+pub use self::button_matrix::*;
+#[cfg(use_lv_calendar)]
+pub use self::calendar::*;
+#[cfg(use_lv_chart)]
+pub use self::chart::*;
+#[cfg(all(use_lv_cb, use_lv_btn))]
+pub use self::checkbox::*;
+#[cfg(use_lv_cont)]
+pub use self::container::*;
+#[cfg(use_lv_ddlist)]
+pub use self::drop_down::*;
 #[cfg(use_lv_gauge)]
 pub use self::gauge::*;
 #[cfg(use_lv_img)]
 pub use self::image::*;
+#[cfg(all(use_lv_imgbtn, use_lv_btn, use_lv_img))]
+pub use self::image_button::*;
+#[cfg(all(use_lv_kb, use_lv_ta, use_lv_label))]
+pub use self::keyboard::*;
 #[cfg(use_lv_label)]
 pub use self::label::*;
+#[cfg(use_lv_led)]
+pub use self::led::*;
+#[cfg(use_lv_line)]
+pub use self::line::*;
 #[cfg(use_lv_lmeter)]
 pub use self::line_meter::*;
+#[cfg(all(use_lv_list, use_lv_btn))]
+pub use self::list::*;
+#[cfg(all(use_lv_mbox, use_lv_btnm))]
+pub use self::message_box::*;
+#[cfg(all(use_lv_page, use_lv_cont))]
+pub use self::page::*;
+#[cfg(use_lv_preload)]
+pub use self::preload::*;
+#[cfg(all(use_lv_roller, use_lv_ddlist))]
+pub use self::roller::*;
+#[cfg(use_lv_slider)]
+pub use self::slider::*;
+#[cfg(use_lv_sw)]
+pub use self::switch::*;
+#[cfg(all(use_lv_tabview, use_lv_page, use_lv_cont))]
+pub use self::tab_view::*;
+#[cfg(all(use_lv_ta, use_lv_label))]
+pub use self::text_area::*;
+#[cfg(all(use_lv_win, use_lv_page, use_lv_cont))]
+pub use self::window::*;
@@ -0,0 +1,28 @@
+use core::ffi::c_void;
+use core::ptr;
+use ffi::*;
+use lvgl_sys::*;
+
+/// Join `options` with new lines into a nul terminated string allocated by
+/// lvgl and pass it to `f`, which has to copy it.
+pub(crate) unsafe fn with_options<R>(options: &[&[u8]], f: impl FnOnce(*const c_char) -> R) -> R {
+    // The separators and the terminating nul.
+    let len = options.iter().map(|option| option.len() + 1).sum::<usize>();
+    let buf = lv_mem_alloc(len.max(1) as u32) as *mut u8;
+    assert!(!buf.is_null(), "Out of lvgl memory");
+
+    let mut pos = 0;
+    for (i, option) in options.iter().enumerate() {
+        if i > 0 {
+            *buf.add(pos) = b'\n';
+            pos += 1;
+        }
+        ptr::copy_nonoverlapping(option.as_ptr(), buf.add(pos), option.len());
+        pos += option.len();
+    }
+    *buf.add(pos) = 0;
+
+    let res = f(buf as *const c_char);
+    lv_mem_free(buf as *const c_void);
+    res
+}
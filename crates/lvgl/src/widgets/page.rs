@@ -0,0 +1,205 @@
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::Layout;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScrollbarMode {
+    /// Never show the scroll bars.
+    Off = LV_SB_MODE_OFF as lv_sb_mode_t,
+    /// Always show the scroll bars.
+    On = LV_SB_MODE_ON as lv_sb_mode_t,
+    /// Show the scroll bars while the page is being dragged.
+    Drag = LV_SB_MODE_DRAG as lv_sb_mode_t,
+    /// Show the scroll bars when the content is larger than the page.
+    Auto = LV_SB_MODE_AUTO as lv_sb_mode_t,
+}
+
+impl ScrollbarMode {
+    #[inline]
+    pub(crate) fn from_lv_sb_mode(mode: lv_sb_mode_t) -> Self {
+        // Ignore the hidden flag.
+        match u32::from(mode) & !LV_SB_MODE_HIDE {
+            LV_SB_MODE_ON => ScrollbarMode::On,
+            LV_SB_MODE_DRAG => ScrollbarMode::Drag,
+            LV_SB_MODE_AUTO => ScrollbarMode::Auto,
+            _ => ScrollbarMode::Off,
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PageStyle {
+    Background = LV_PAGE_STYLE_BG as lv_page_style_t,
+    Scrollable = LV_PAGE_STYLE_SCRL as lv_page_style_t,
+    Scrollbar = LV_PAGE_STYLE_SB as lv_page_style_t,
+    EdgeFlash = LV_PAGE_STYLE_EDGE_FLASH as lv_page_style_t,
+}
+
+/// A scrollable page. The children created on the page are moved to its
+/// scrollable part.
+#[repr(transparent)]
+pub struct Page(*mut lv_obj_t);
+
+impl Page {
+    /// Create a new page on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new page as a child of `parent`, optionally copying another
+    /// page.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Page>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let page = lv_page_create(parent.as_lv_obj(), copy);
+            if page.is_null() {
+                None
+            } else {
+                Some(Child::new(Page(page)))
+            }
+        }
+    }
+
+    // Scrolling.
+
+    /// Scroll the page to show a child, animated for `anim_time` ms.
+    #[inline]
+    pub fn focus(&mut self, child: &impl ObjT, anim_time: u16) {
+        unsafe {
+            lv_page_focus(self.0, child.as_lv_obj(), anim_time);
+        }
+    }
+
+    /// Scroll the page horizontally.
+    #[inline]
+    pub fn scroll_hor(&mut self, dist: lv_coord_t) {
+        unsafe {
+            lv_page_scroll_hor(self.0, dist);
+        }
+    }
+
+    /// Scroll the page vertically.
+    #[inline]
+    pub fn scroll_ver(&mut self, dist: lv_coord_t) {
+        unsafe {
+            lv_page_scroll_ver(self.0, dist);
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the layout of the scrollable part.
+    #[inline]
+    pub fn set_scrl_layout(&mut self, layout: Layout) {
+        unsafe {
+            // NOTE: call the cont function directly.
+            lv_cont_set_layout(lv_page_get_scrl(self.0), layout as lv_layout_t);
+        }
+    }
+
+    /// Enable the horizontal or vertical fit of the scrollable part to its
+    /// children.
+    #[inline]
+    pub fn set_scrl_fit(&mut self, hor: bool, ver: bool) {
+        unsafe {
+            // NOTE: call the cont function directly.
+            lv_cont_set_fit(lv_page_get_scrl(self.0), hor, ver);
+        }
+    }
+
+    /// Set the scroll bar mode.
+    #[inline]
+    pub fn set_sb_mode(&mut self, mode: ScrollbarMode) {
+        unsafe {
+            lv_page_set_sb_mode(self.0, mode as lv_sb_mode_t);
+        }
+    }
+
+    /// Enable scrolling with the arrow keys.
+    #[inline]
+    pub fn set_arrow_scroll(&mut self, enabled: bool) {
+        unsafe {
+            lv_page_set_arrow_scroll(self.0, enabled);
+        }
+    }
+
+    /// Enable scrolling the parent page once this one reached its edge.
+    #[inline]
+    pub fn set_scroll_propagation(&mut self, enabled: bool) {
+        unsafe {
+            lv_page_set_scroll_propagation(self.0, enabled);
+        }
+    }
+
+    /// Enable the edge flash effect.
+    #[inline]
+    pub fn set_edge_flash(&mut self, enabled: bool) {
+        unsafe {
+            lv_page_set_edge_flash(self.0, enabled);
+        }
+    }
+
+    /// Set the style of a part of the page.
+    #[inline]
+    pub fn set_part_style(&mut self, part: PageStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_page_set_style(self.0, part as lv_page_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the scroll bar mode.
+    #[inline]
+    pub fn get_sb_mode(&self) -> ScrollbarMode {
+        ScrollbarMode::from_lv_sb_mode(unsafe { lv_page_get_sb_mode(self.0) })
+    }
+
+    /// Get whether scrolling with the arrow keys is enabled.
+    #[inline]
+    pub fn get_arrow_scroll(&self) -> bool {
+        unsafe { lv_page_get_arrow_scroll(self.0) }
+    }
+
+    /// Get the width available for the children, without the paddings.
+    #[inline]
+    pub fn get_fit_width(&self) -> lv_coord_t {
+        unsafe { lv_page_get_fit_width(self.0) }
+    }
+
+    /// Get the height available for the children, without the paddings.
+    #[inline]
+    pub fn get_fit_height(&self) -> lv_coord_t {
+        unsafe { lv_page_get_fit_height(self.0) }
+    }
+}
+
+impl ObjT for Page {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Page(obj)
+    }
+}
+
+impl Drop for Page {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
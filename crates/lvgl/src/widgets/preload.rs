@@ -0,0 +1,113 @@
+use crate::obj::{Child, ObjT, Owned};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PreloadType {
+    /// An arc of constant length spinning around.
+    SpinningArc = LV_PRELOAD_TYPE_SPINNING_ARC as lv_preloader_type_t,
+    /// A spinning arc which fills the circle and then shrinks again.
+    FillSpinArc = LV_PRELOAD_TYPE_FILLSPIN_ARC as lv_preloader_type_t,
+}
+
+/// A spinner showing that something is in progress.
+#[repr(transparent)]
+pub struct Preload(*mut lv_obj_t);
+
+impl Preload {
+    /// Create a new spinner on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new spinner as a child of `parent`, optionally copying another
+    /// spinner.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Preload>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let preload = lv_preload_create(parent.as_lv_obj(), copy);
+            if preload.is_null() {
+                None
+            } else {
+                Some(Child::new(Preload(preload)))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the length of the spinning arc in degrees.
+    #[inline]
+    pub fn set_arc_length(&mut self, deg: u16) {
+        unsafe {
+            lv_preload_set_arc_length(self.0, deg);
+        }
+    }
+
+    /// Set the time of one turn in milliseconds.
+    #[inline]
+    pub fn set_spin_time(&mut self, time: u16) {
+        unsafe {
+            lv_preload_set_spin_time(self.0, time);
+        }
+    }
+
+    /// Set the animation type of the spinner.
+    #[inline]
+    pub fn set_animation_type(&mut self, preload_type: PreloadType) {
+        unsafe {
+            lv_preload_set_animation_type(self.0, preload_type as lv_preloader_type_t);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the length of the spinning arc in degrees.
+    #[inline]
+    pub fn get_arc_length(&self) -> u16 {
+        unsafe { lv_preload_get_arc_length(self.0) }
+    }
+
+    /// Get the time of one turn in milliseconds.
+    #[inline]
+    pub fn get_spin_time(&self) -> u16 {
+        unsafe { lv_preload_get_spin_time(self.0) }
+    }
+
+    /// Get the animation type of the spinner.
+    #[inline]
+    pub fn get_animation_type(&self) -> PreloadType {
+        let preload_type = unsafe { lv_preload_get_animation_type(self.0) };
+        if preload_type == LV_PRELOAD_TYPE_FILLSPIN_ARC as lv_preloader_type_t {
+            PreloadType::FillSpinArc
+        } else {
+            PreloadType::SpinningArc
+        }
+    }
+}
+
+impl ObjT for Preload {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Preload(obj)
+    }
+}
+
+impl Drop for Preload {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
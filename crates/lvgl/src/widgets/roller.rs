@@ -0,0 +1,136 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::options;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RollerStyle {
+    Background = LV_ROLLER_STYLE_BG as lv_roller_style_t,
+    Selected = LV_ROLLER_STYLE_SEL as lv_roller_style_t,
+}
+
+/// A roller, sending `Event::ValueChanged` to its event handler when the user
+/// has selected an option.
+#[repr(transparent)]
+pub struct Roller(*mut lv_obj_t);
+
+impl Roller {
+    /// Create a new roller on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new roller as a child of `parent`, optionally copying another
+    /// roller.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Roller>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_roller_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(roller: *mut lv_obj_t) -> Option<Self> {
+        if roller.is_null() {
+            None
+        } else {
+            // NOTE: call the ddlist function directly.
+            lv_ddlist_set_action(roller, Some(event::value_changed_action));
+            Some(Roller(roller))
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the options, given without new lines and terminating nul. They
+    /// are copied by the roller.
+    #[inline]
+    pub fn set_options(&mut self, options: &[&[u8]]) {
+        unsafe {
+            // NOTE: call the ddlist function directly.
+            options::with_options(options, |options| lv_ddlist_set_options(self.0, options));
+        }
+    }
+
+    /// Set the selected option, scrolling the roller to it.
+    #[inline]
+    pub fn set_selected(&mut self, index: u16, anim: bool) {
+        unsafe {
+            lv_roller_set_selected(self.0, index, anim);
+        }
+    }
+
+    /// Set the height of the roller to show the given number of rows.
+    #[inline]
+    pub fn set_visible_row_count(&mut self, row_count: u8) {
+        unsafe {
+            lv_roller_set_visible_row_count(self.0, row_count);
+        }
+    }
+
+    /// Set the scroll animation time.
+    #[inline]
+    pub fn set_anim_time(&mut self, anim_time: u16) {
+        unsafe {
+            // NOTE: call the ddlist function directly.
+            lv_ddlist_set_anim_time(self.0, anim_time);
+        }
+    }
+
+    /// Set the style of a part of the roller.
+    #[inline]
+    pub fn set_part_style(&mut self, part: RollerStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_roller_set_style(self.0, part as lv_roller_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the index of the selected option.
+    #[inline]
+    pub fn get_selected(&self) -> u16 {
+        unsafe {
+            // NOTE: call the ddlist function directly.
+            lv_ddlist_get_selected(self.0)
+        }
+    }
+
+    /// Get the scroll animation time.
+    #[inline]
+    pub fn get_anim_time(&self) -> u16 {
+        unsafe {
+            // NOTE: call the ddlist function directly.
+            lv_ddlist_get_anim_time(self.0)
+        }
+    }
+}
+
+impl ObjT for Roller {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Roller(obj)
+    }
+}
+
+impl Drop for Roller {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SliderStyle {
+    Background = LV_SLIDER_STYLE_BG as lv_slider_style_t,
+    Indicator = LV_SLIDER_STYLE_INDIC as lv_slider_style_t,
+    Knob = LV_SLIDER_STYLE_KNOB as lv_slider_style_t,
+}
+
+/// A slider, sending `Event::ValueChanged` to its event handler when the
+/// user has changed its value.
+#[repr(transparent)]
+pub struct Slider(*mut lv_obj_t);
+
+impl Slider {
+    /// Create a new slider on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new slider as a child of `parent`, optionally copying another
+    /// slider.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Slider>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_slider_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(slider: *mut lv_obj_t) -> Option<Self> {
+        if slider.is_null() {
+            None
+        } else {
+            lv_slider_set_action(slider, Some(event::value_changed_action));
+            Some(Slider(slider))
+        }
+    }
+
+    // Setter methods.
+
+    /// Set a new value on the slider.
+    #[inline]
+    pub fn set_value(&mut self, value: i16) {
+        unsafe {
+            // NOTE: call the bar function directly.
+            lv_bar_set_value(self.0, value);
+        }
+    }
+
+    /// Set a new value with animation on the slider.
+    #[inline]
+    pub fn set_value_anim(&mut self, value: i16, anim_time: u16) {
+        unsafe {
+            // NOTE: call the bar function directly.
+            lv_bar_set_value_anim(self.0, value, anim_time);
+        }
+    }
+
+    /// Set minimum and the maximum values of the slider.
+    #[inline]
+    pub fn set_range(&mut self, min: i16, max: i16) {
+        unsafe {
+            // NOTE: call the bar function directly.
+            lv_bar_set_range(self.0, min, max);
+        }
+    }
+
+    /// Keep the knob inside the slider instead of centering it on the edges.
+    #[inline]
+    pub fn set_knob_in(&mut self, knob_in: bool) {
+        unsafe {
+            lv_slider_set_knob_in(self.0, knob_in);
+        }
+    }
+
+    /// Set the style of a part of the slider.
+    #[inline]
+    pub fn set_part_style(&mut self, part: SliderStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_slider_set_style(self.0, part as lv_slider_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the value of the slider, including while it is being dragged.
+    #[inline]
+    pub fn get_value(&self) -> i16 {
+        unsafe { lv_slider_get_value(self.0) }
+    }
+
+    /// Get the minimum value of the slider.
+    #[inline]
+    pub fn get_min_value(&self) -> i16 {
+        unsafe {
+            // NOTE: call the bar function directly.
+            lv_bar_get_min_value(self.0)
+        }
+    }
+
+    /// Get the maximum value of the slider.
+    #[inline]
+    pub fn get_max_value(&self) -> i16 {
+        unsafe {
+            // NOTE: call the bar function directly.
+            lv_bar_get_max_value(self.0)
+        }
+    }
+
+    /// Check whether the slider is being dragged.
+    #[inline]
+    pub fn is_dragged(&self) -> bool {
+        unsafe { lv_slider_is_dragged(self.0) }
+    }
+
+    /// Get whether the knob is kept inside the slider.
+    #[inline]
+    pub fn get_knob_in(&self) -> bool {
+        unsafe { lv_slider_get_knob_in(self.0) }
+    }
+}
+
+impl ObjT for Slider {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Slider(obj)
+    }
+}
+
+impl Drop for Slider {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
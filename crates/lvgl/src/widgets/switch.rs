@@ -0,0 +1,105 @@
+use crate::event;
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SwitchStyle {
+    Background = LV_SW_STYLE_BG as lv_sw_style_t,
+    Indicator = LV_SW_STYLE_INDIC as lv_sw_style_t,
+    KnobOff = LV_SW_STYLE_KNOB_OFF as lv_sw_style_t,
+    KnobOn = LV_SW_STYLE_KNOB_ON as lv_sw_style_t,
+}
+
+/// A switch, sending `Event::ValueChanged` to its event handler when the
+/// user has toggled it.
+#[repr(transparent)]
+pub struct Switch(*mut lv_obj_t);
+
+impl Switch {
+    /// Create a new switch on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new switch as a child of `parent`, optionally copying another
+    /// switch.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Switch>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_sw_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(sw: *mut lv_obj_t) -> Option<Self> {
+        if sw.is_null() {
+            None
+        } else {
+            // NOTE: call the slider function directly.
+            lv_slider_set_action(sw, Some(event::value_changed_action));
+            Some(Switch(sw))
+        }
+    }
+
+    // Setter methods.
+
+    /// Turn the switch on or off.
+    #[inline]
+    pub fn set_state(&mut self, on: bool) {
+        unsafe {
+            if on {
+                lv_sw_on(self.0);
+            } else {
+                lv_sw_off(self.0);
+            }
+        }
+    }
+
+    /// Set the style of a part of the switch.
+    #[inline]
+    pub fn set_part_style(&mut self, part: SwitchStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_sw_set_style(self.0, part as lv_sw_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get whether the switch is on.
+    #[inline]
+    pub fn get_state(&self) -> bool {
+        unsafe {
+            // NOTE: call the bar function directly.
+            lv_bar_get_value(self.0) == 1
+        }
+    }
+}
+
+impl ObjT for Switch {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Switch(obj)
+    }
+}
+
+impl Drop for Switch {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
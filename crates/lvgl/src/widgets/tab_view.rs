@@ -0,0 +1,200 @@
+use crate::event::{self, Event};
+use crate::obj::{Child, ObjRef, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::{options, Page};
+
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TabButtonsPos {
+    Top = LV_TABVIEW_BTNS_POS_TOP as lv_tabview_btns_pos_t,
+    Bottom = LV_TABVIEW_BTNS_POS_BOTTOM as lv_tabview_btns_pos_t,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TabViewStyle {
+    Background = LV_TABVIEW_STYLE_BG as lv_tabview_style_t,
+    Indicator = LV_TABVIEW_STYLE_INDIC as lv_tabview_style_t,
+    ButtonsBackground = LV_TABVIEW_STYLE_BTN_BG as lv_tabview_style_t,
+    ButtonReleased = LV_TABVIEW_STYLE_BTN_REL as lv_tabview_style_t,
+    ButtonPressed = LV_TABVIEW_STYLE_BTN_PR as lv_tabview_style_t,
+    ButtonToggledReleased = LV_TABVIEW_STYLE_BTN_TGL_REL as lv_tabview_style_t,
+    ButtonToggledPressed = LV_TABVIEW_STYLE_BTN_TGL_PR as lv_tabview_style_t,
+}
+
+/// A tab view holding a page per tab, sending `Event::ValueChanged` to its
+/// event handler when a tab has been loaded.
+#[repr(transparent)]
+pub struct TabView(*mut lv_obj_t);
+
+impl TabView {
+    /// Create a new tab view on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new tab view as a child of `parent`, optionally copying
+    /// another tab view.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&TabView>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            Self::from_new(lv_tabview_create(parent.as_lv_obj(), copy)).map(|obj| Child::new(obj))
+        }
+    }
+
+    #[inline]
+    unsafe fn from_new(tabview: *mut lv_obj_t) -> Option<Self> {
+        if tabview.is_null() {
+            None
+        } else {
+            lv_tabview_set_tab_load_action(tabview, Some(tab_load_action));
+            Some(TabView(tabview))
+        }
+    }
+
+    /// Add a new tab with the given name, given without terminating nul, and
+    /// return its page, which is deleted together with the tab view.
+    #[inline]
+    pub fn add_tab(&self, name: &[u8]) -> Option<Child<'_, Page>> {
+        unsafe {
+            let tab = options::with_options(&[name], |name| lv_tabview_add_tab(self.0, name));
+            if tab.is_null() {
+                None
+            } else {
+                Some(Child::from_lv_obj(tab))
+            }
+        }
+    }
+
+    // Setter methods.
+
+    /// Show a tab, with or without the sliding animation.
+    #[inline]
+    pub fn set_tab_act(&mut self, id: u16, anim: bool) {
+        unsafe {
+            lv_tabview_set_tab_act(self.0, id, anim);
+        }
+    }
+
+    /// Enable switching the tabs by sliding the pages.
+    #[inline]
+    pub fn set_sliding(&mut self, enabled: bool) {
+        unsafe {
+            lv_tabview_set_sliding(self.0, enabled);
+        }
+    }
+
+    /// Set the sliding animation time.
+    #[inline]
+    pub fn set_anim_time(&mut self, anim_time: u16) {
+        unsafe {
+            lv_tabview_set_anim_time(self.0, anim_time);
+        }
+    }
+
+    /// Set the position of the tab buttons.
+    #[inline]
+    pub fn set_btns_pos(&mut self, pos: TabButtonsPos) {
+        unsafe {
+            lv_tabview_set_btns_pos(self.0, pos as lv_tabview_btns_pos_t);
+        }
+    }
+
+    /// Hide or show the tab buttons.
+    #[inline]
+    pub fn set_btns_hidden(&mut self, hidden: bool) {
+        unsafe {
+            lv_tabview_set_btns_hidden(self.0, hidden);
+        }
+    }
+
+    /// Set the style of a part of the tab view.
+    #[inline]
+    pub fn set_part_style(&mut self, part: TabViewStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_tabview_set_style(self.0, part as lv_tabview_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the index of the shown tab.
+    #[inline]
+    pub fn get_tab_act(&self) -> u16 {
+        unsafe { lv_tabview_get_tab_act(self.0) }
+    }
+
+    /// Get the number of tabs.
+    #[inline]
+    pub fn get_tab_count(&self) -> u16 {
+        unsafe { lv_tabview_get_tab_count(self.0) }
+    }
+
+    /// Get the page of a tab.
+    #[inline]
+    pub fn get_tab(&self, id: u16) -> Option<ObjRef<'_>> {
+        unsafe {
+            let tab = lv_tabview_get_tab(self.0, id);
+            if tab.is_null() {
+                None
+            } else {
+                Some(ObjRef::from_raw(tab))
+            }
+        }
+    }
+
+    /// Get whether switching the tabs by sliding is enabled.
+    #[inline]
+    pub fn get_sliding(&self) -> bool {
+        unsafe { lv_tabview_get_sliding(self.0) }
+    }
+
+    /// Get the sliding animation time.
+    #[inline]
+    pub fn get_anim_time(&self) -> u16 {
+        unsafe { lv_tabview_get_anim_time(self.0) }
+    }
+
+    /// Get the position of the tab buttons.
+    #[inline]
+    pub fn get_btns_pos(&self) -> TabButtonsPos {
+        let pos = unsafe { lv_tabview_get_btns_pos(self.0) };
+        if pos == LV_TABVIEW_BTNS_POS_BOTTOM as lv_tabview_btns_pos_t {
+            TabButtonsPos::Bottom
+        } else {
+            TabButtonsPos::Top
+        }
+    }
+}
+
+impl ObjT for TabView {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        TabView(obj)
+    }
+}
+
+impl Drop for TabView {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+unsafe extern "C" fn tab_load_action(tabview: *mut lv_obj_t, _id: u16) {
+    event::dispatch(tabview, Event::ValueChanged);
+}
@@ -0,0 +1,341 @@
+use crate::obj::{Child, ObjT, Owned};
+use crate::style::Style;
+use crate::widgets::{options, LabelAlign};
+
+use core::ptr;
+use core::slice;
+use ffi::*;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CursorType {
+    None = LV_CURSOR_NONE as lv_cursor_type_t,
+    Line = LV_CURSOR_LINE as lv_cursor_type_t,
+    Block = LV_CURSOR_BLOCK as lv_cursor_type_t,
+    Outline = LV_CURSOR_OUTLINE as lv_cursor_type_t,
+    Underline = LV_CURSOR_UNDERLINE as lv_cursor_type_t,
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextAreaStyle {
+    Background = LV_TA_STYLE_BG as lv_ta_style_t,
+    Scrollbar = LV_TA_STYLE_SB as lv_ta_style_t,
+    EdgeFlash = LV_TA_STYLE_EDGE_FLASH as lv_ta_style_t,
+    Cursor = LV_TA_STYLE_CURSOR as lv_ta_style_t,
+}
+
+#[repr(transparent)]
+pub struct TextArea(*mut lv_obj_t);
+
+impl TextArea {
+    /// Cursor position after the last character.
+    pub const CURSOR_LAST: i16 = LV_TA_CURSOR_LAST as i16;
+
+    /// Create a new text area on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new text area as a child of `parent`, optionally copying
+    /// another text area.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&TextArea>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let ta = lv_ta_create(parent.as_lv_obj(), copy);
+            if ta.is_null() {
+                None
+            } else {
+                Some(Child::new(TextArea(ta)))
+            }
+        }
+    }
+
+    // Text editing.
+
+    /// Insert a character at the cursor position.
+    #[inline]
+    pub fn add_char(&mut self, c: char) {
+        // lvgl reads the UTF-8 bytes of the character from the memory of the
+        // integer.
+        let mut bytes = [0; 4];
+        c.encode_utf8(&mut bytes);
+        unsafe {
+            lv_ta_add_char(self.0, u32::from_ne_bytes(bytes));
+        }
+    }
+
+    /// Insert a text, given without terminating nul, at the cursor position.
+    #[inline]
+    pub fn add_text(&mut self, text: &[u8]) {
+        unsafe {
+            options::with_options(&[text], |text| lv_ta_add_text(self.0, text));
+        }
+    }
+
+    /// Delete the character left of the cursor.
+    #[inline]
+    pub fn del_char(&mut self) {
+        unsafe {
+            lv_ta_del_char(self.0);
+        }
+    }
+
+    // Cursor control.
+
+    /// Move the cursor one character right.
+    #[inline]
+    pub fn cursor_right(&mut self) {
+        unsafe {
+            lv_ta_cursor_right(self.0);
+        }
+    }
+
+    /// Move the cursor one character left.
+    #[inline]
+    pub fn cursor_left(&mut self) {
+        unsafe {
+            lv_ta_cursor_left(self.0);
+        }
+    }
+
+    /// Move the cursor one line down.
+    #[inline]
+    pub fn cursor_down(&mut self) {
+        unsafe {
+            lv_ta_cursor_down(self.0);
+        }
+    }
+
+    /// Move the cursor one line up.
+    #[inline]
+    pub fn cursor_up(&mut self) {
+        unsafe {
+            lv_ta_cursor_up(self.0);
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the text of the text area, given without terminating nul. Memory
+    /// will be allocated to store the text by the text area.
+    #[inline]
+    pub fn set_text(&mut self, text: &[u8]) {
+        unsafe {
+            options::with_options(&[text], |text| lv_ta_set_text(self.0, text));
+        }
+    }
+
+    /// Set the cursor position. A negative position counts from the end of
+    /// the text, use `CURSOR_LAST` to put it after the last character.
+    #[inline]
+    pub fn set_cursor_pos(&mut self, pos: i16) {
+        unsafe {
+            lv_ta_set_cursor_pos(self.0, pos);
+        }
+    }
+
+    /// Set the type of the cursor.
+    #[inline]
+    pub fn set_cursor_type(&mut self, cursor_type: CursorType) {
+        unsafe {
+            lv_ta_set_cursor_type(self.0, cursor_type as lv_cursor_type_t);
+        }
+    }
+
+    /// Enable or disable the password mode, hiding the characters.
+    #[inline]
+    pub fn set_pwd_mode(&mut self, enabled: bool) {
+        unsafe {
+            lv_ta_set_pwd_mode(self.0, enabled);
+        }
+    }
+
+    /// Configure the text area to one line or back to normal.
+    #[inline]
+    pub fn set_one_line(&mut self, enabled: bool) {
+        unsafe {
+            lv_ta_set_one_line(self.0, enabled);
+        }
+    }
+
+    /// Set the alignment of the text.
+    #[inline]
+    pub fn set_text_align(&mut self, align: LabelAlign) {
+        unsafe {
+            lv_ta_set_text_align(self.0, align as lv_label_align_t);
+        }
+    }
+
+    /// Set the list of characters accepted by the text area, given with the
+    /// terminating nul. All other characters are ignored. The list is not
+    /// copied by the text area.
+    #[inline]
+    pub fn set_accepted_chars(&mut self, chars: &'static [u8]) {
+        assert_eq!(chars.last(), Some(&0), "Accepted chars not nul terminated");
+        unsafe {
+            lv_ta_set_accepted_chars(self.0, chars.as_ptr() as *const c_char);
+        }
+    }
+
+    /// Accept any character again.
+    #[inline]
+    pub fn clear_accepted_chars(&mut self) {
+        unsafe {
+            lv_ta_set_accepted_chars(self.0, ptr::null());
+        }
+    }
+
+    /// Set the maximal number of characters, 0 means no limit.
+    #[inline]
+    pub fn set_max_length(&mut self, len: u16) {
+        unsafe {
+            lv_ta_set_max_length(self.0, len);
+        }
+    }
+
+    /// Set a text, given without terminating nul, shown dimmed while the text
+    /// area is empty.
+    ///
+    /// lvgl has no placeholder of its own, so it is emulated by a child label
+    /// which is not drawn as long as the text area has a text.
+    pub fn set_placeholder(&mut self, text: &[u8]) {
+        unsafe {
+            let label = match self.find_placeholder() {
+                Some(label) => label,
+                None => {
+                    // The page moves the label to its scrollable, next to the
+                    // label of the text.
+                    let label = lv_label_create(self.0, ptr::null());
+                    assert!(!label.is_null(), "Out of lvgl memory");
+                    let text_label = lv_ta_get_label(self.0);
+                    let x = lv_obj_get_x(text_label);
+                    let y = lv_obj_get_y(text_label);
+                    lv_obj_set_pos(label, x, y);
+                    lv_obj_set_opa_scale_enable(label, true);
+                    lv_obj_set_opa_scale(label, LV_OPA_50 as lv_opa_t);
+                    lv_obj_set_design_func(label, Some(placeholder_design));
+                    label
+                }
+            };
+            options::with_options(&[text], |text| lv_label_set_text(label, text));
+        }
+    }
+
+    /// Set the style of a part of the text area.
+    #[inline]
+    pub fn set_part_style(&mut self, part: TextAreaStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_ta_set_style(self.0, part as lv_ta_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the text of the text area, without the terminating nul.
+    #[inline]
+    pub fn get_text(&self) -> &[u8] {
+        unsafe {
+            let text = lv_ta_get_text(self.0);
+            let mut len = 0;
+            while *text.add(len) != 0 {
+                len += 1;
+            }
+            slice::from_raw_parts(text as *const u8, len)
+        }
+    }
+
+    /// Get the cursor position.
+    #[inline]
+    pub fn get_cursor_pos(&self) -> u16 {
+        unsafe { lv_ta_get_cursor_pos(self.0) }
+    }
+
+    /// Get the type of the cursor.
+    #[inline]
+    pub fn get_cursor_type(&self) -> CursorType {
+        let cursor_type = unsafe { lv_ta_get_cursor_type(self.0) };
+        // Ignore the hidden flag.
+        match u32::from(cursor_type) & !LV_CURSOR_HIDDEN {
+            LV_CURSOR_LINE => CursorType::Line,
+            LV_CURSOR_BLOCK => CursorType::Block,
+            LV_CURSOR_OUTLINE => CursorType::Outline,
+            LV_CURSOR_UNDERLINE => CursorType::Underline,
+            _ => CursorType::None,
+        }
+    }
+
+    /// Get whether the password mode is enabled.
+    #[inline]
+    pub fn get_pwd_mode(&self) -> bool {
+        unsafe { lv_ta_get_pwd_mode(self.0) }
+    }
+
+    /// Get whether the text area is configured to one line.
+    #[inline]
+    pub fn get_one_line(&self) -> bool {
+        unsafe { lv_ta_get_one_line(self.0) }
+    }
+
+    /// Get the maximal number of characters, 0 means no limit.
+    #[inline]
+    pub fn get_max_length(&self) -> u16 {
+        unsafe { lv_ta_get_max_length(self.0) }
+    }
+
+    unsafe fn find_placeholder(&self) -> Option<*mut lv_obj_t> {
+        let design: lv_design_func_t = Some(placeholder_design);
+        let scrl = lv_obj_get_parent(lv_ta_get_label(self.0));
+        let mut child = lv_obj_get_child(scrl, ptr::null());
+        while !child.is_null() {
+            if lv_obj_get_design_func(child) == design {
+                return Some(child);
+            }
+            child = lv_obj_get_child(scrl, child);
+        }
+        None
+    }
+}
+
+impl ObjT for TextArea {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        TextArea(obj)
+    }
+}
+
+impl Drop for TextArea {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+unsafe extern "C" fn placeholder_design(
+    label: *mut lv_obj_t,
+    mask: *const lv_area_t,
+    mode: lv_design_mode_t,
+) -> bool {
+    // The placeholder lives on the scrollable of the text area.
+    let ta = lv_obj_get_parent(lv_obj_get_parent(label));
+    if mode == LV_DESIGN_DRAW_MAIN as lv_design_mode_t && *lv_ta_get_text(ta) != 0 {
+        return true;
+    }
+    // The label of the text keeps the design function of the labels.
+    match lv_obj_get_design_func(lv_ta_get_label(ta)) {
+        Some(design) => design(label, mask, mode),
+        None => true,
+    }
+}
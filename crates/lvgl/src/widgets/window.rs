@@ -0,0 +1,186 @@
+#[cfg(use_lv_img)]
+use crate::callback;
+use crate::obj::{Child, ObjRef, ObjT, Owned};
+use crate::style::Style;
+#[cfg(use_lv_img)]
+use crate::widgets::ImageSource;
+use crate::widgets::{options, Layout, ScrollbarMode};
+
+#[cfg(use_lv_img)]
+use core::mem::ManuallyDrop;
+use core::ptr;
+use lvgl_sys::*;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WindowStyle {
+    Background = LV_WIN_STYLE_BG as lv_win_style_t,
+    ContentBackground = LV_WIN_STYLE_CONTENT_BG as lv_win_style_t,
+    ContentScrollable = LV_WIN_STYLE_CONTENT_SCRL as lv_win_style_t,
+    Scrollbar = LV_WIN_STYLE_SB as lv_win_style_t,
+    Header = LV_WIN_STYLE_HEADER as lv_win_style_t,
+    ButtonReleased = LV_WIN_STYLE_BTN_REL as lv_win_style_t,
+    ButtonPressed = LV_WIN_STYLE_BTN_PR as lv_win_style_t,
+}
+
+/// A window with a header holding a title and control buttons, and a
+/// scrollable content. The children created on the window are moved to its
+/// content.
+#[repr(transparent)]
+pub struct Window(*mut lv_obj_t);
+
+impl Window {
+    /// Create a new window on `parent`, deleted when the returned handle is
+    /// dropped.
+    #[inline]
+    pub fn new(parent: &impl ObjT) -> Option<Owned<'_, Self>> {
+        Self::create(parent, None).map(Child::into_owned)
+    }
+
+    /// Create a new window as a child of `parent`, optionally copying another
+    /// window.
+    #[inline]
+    pub fn create<'a>(parent: &'a impl ObjT, copy: Option<&Window>) -> Option<Child<'a, Self>> {
+        unsafe {
+            let copy = copy.map_or(ptr::null(), |copy| copy.0 as *const _);
+            let win = lv_win_create(parent.as_lv_obj(), copy);
+            if win.is_null() {
+                None
+            } else {
+                Some(Child::new(Window(win)))
+            }
+        }
+    }
+
+    /// Add a control button with an image to the header, calling `action`
+    /// when it is released. The closure must not capture any variables or
+    /// delete the window.
+    #[cfg(use_lv_img)]
+    #[inline]
+    pub fn add_btn<F>(&mut self, src: &'static dyn ImageSource, action: F) -> bool
+    where
+        F: Fn(&mut Window) + Copy + 'static,
+    {
+        callback::assert_zero_sized(&action);
+        unsafe {
+            let src = src.as_img_src_ptr();
+            !lv_win_add_btn(self.0, src, Some(btn_trampoline::<F>)).is_null()
+        }
+    }
+
+    /// Scroll the content to show a child, animated for `anim_time` ms.
+    #[inline]
+    pub fn focus(&mut self, child: &impl ObjT, anim_time: u16) {
+        unsafe {
+            lv_win_focus(self.0, child.as_lv_obj(), anim_time);
+        }
+    }
+
+    // Setter methods.
+
+    /// Set the title of the window, given without terminating nul. Memory
+    /// will be allocated to store the text by the window.
+    #[inline]
+    pub fn set_title(&mut self, title: &[u8]) {
+        unsafe {
+            options::with_options(&[title], |title| lv_win_set_title(self.0, title));
+        }
+    }
+
+    /// Set the size of the control buttons.
+    #[inline]
+    pub fn set_btn_size(&mut self, size: lv_coord_t) {
+        unsafe {
+            lv_win_set_btn_size(self.0, size);
+        }
+    }
+
+    /// Set the layout of the content.
+    #[inline]
+    pub fn set_layout(&mut self, layout: Layout) {
+        unsafe {
+            lv_win_set_layout(self.0, layout as lv_layout_t);
+        }
+    }
+
+    /// Set the scroll bar mode of the content.
+    #[inline]
+    pub fn set_sb_mode(&mut self, mode: ScrollbarMode) {
+        unsafe {
+            lv_win_set_sb_mode(self.0, mode as lv_sb_mode_t);
+        }
+    }
+
+    /// Set the style of a part of the window.
+    #[inline]
+    pub fn set_part_style(&mut self, part: WindowStyle, style: &'static Style) {
+        let style = style.as_lv_style();
+        unsafe {
+            lv_win_set_style(self.0, part as lv_win_style_t, style);
+        }
+    }
+
+    // Getter methods.
+
+    /// Get the content page of the window.
+    #[inline]
+    pub fn get_content(&self) -> ObjRef<'_> {
+        unsafe { ObjRef::from_raw(lv_win_get_content(self.0)) }
+    }
+
+    /// Get the size of the control buttons.
+    #[inline]
+    pub fn get_btn_size(&self) -> lv_coord_t {
+        unsafe { lv_win_get_btn_size(self.0) }
+    }
+
+    /// Get the layout of the content.
+    #[inline]
+    pub fn get_layout(&self) -> Layout {
+        Layout::from_lv_layout(unsafe { lv_win_get_layout(self.0) })
+    }
+
+    /// Get the scroll bar mode of the content.
+    #[inline]
+    pub fn get_sb_mode(&self) -> ScrollbarMode {
+        ScrollbarMode::from_lv_sb_mode(unsafe { lv_win_get_sb_mode(self.0) })
+    }
+
+    /// Get the width available for the children of the content.
+    #[inline]
+    pub fn get_width(&self) -> lv_coord_t {
+        unsafe { lv_win_get_width(self.0) }
+    }
+}
+
+impl ObjT for Window {
+    #[inline]
+    unsafe fn as_lv_obj(&self) -> *mut lv_obj_t {
+        self.0
+    }
+
+    #[inline]
+    unsafe fn from_lv_obj(obj: *mut lv_obj_t) -> Self {
+        Window(obj)
+    }
+}
+
+impl Drop for Window {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            lv_obj_del(self.0);
+        }
+    }
+}
+
+#[cfg(use_lv_img)]
+unsafe extern "C" fn btn_trampoline<F>(btn: *mut lv_obj_t) -> lv_res_t
+where
+    F: Fn(&mut Window) + Copy,
+{
+    let action: F = callback::recreate();
+    let win = lv_win_get_from_btn(btn);
+    action(&mut ManuallyDrop::new(Window(win)));
+    LV_RES_OK as lv_res_t
+}
@@ -12,6 +12,7 @@
 #include <string.h>
 
 #include "tft.h"
+#include "display.h"
 #include "stm32f4xx.h"
 #include "ili9341.h"
 
@@ -64,6 +65,7 @@ static void tft_fill(int32_t x1, int32_t y1, int32_t x2, int32_t y2, lv_color_t
 static void tft_map(int32_t x1, int32_t y1, int32_t x2, int32_t y2, const lv_color_t * color_p);
 static void tft_flush(int32_t x1, int32_t y1, int32_t x2, int32_t y2, const lv_color_t * color_p);
 #if TFT_USE_GPU != 0
+#error "The display registered through the lvgl crate has no GPU callbacks"
 static void gpu_mem_blend(lv_color_t * dest, const lv_color_t * src, uint32_t length, lv_opa_t opa);
 static void gpu_mem_fill(lv_color_t * dest, uint32_t length, lv_color_t color);
 #endif
@@ -129,8 +131,11 @@ static const lv_color_t * buf_to_flush;
  */
 void tft_init(void)
 {
-	lv_disp_drv_t disp_drv;
-	lv_disp_drv_init(&disp_drv);
+	static const DisplayCallbacks callbacks = {
+		.flush = tft_flush,
+		.fill = tft_fill,
+		.map = tft_map,
+	};
 
 #if TFT_EXT_FB != 0
 	SDRAM_Init();
@@ -138,15 +143,8 @@ void tft_init(void)
 	LCD_Config();
 	DMA_Config();
 
-	disp_drv.disp_fill = tft_fill;
-	disp_drv.disp_map = tft_map;
-	disp_drv.disp_flush = tft_flush;
-#if TFT_USE_GPU != 0
-	DMA2D_Config();
-	disp_drv.mem_blend = gpu_mem_blend;
-	disp_drv.mem_fill = gpu_mem_fill;
-#endif
-	lv_disp_drv_register(&disp_drv);
+	/* The display is registered through the lvgl crate */
+	display_register(&callbacks);
 }
 
 /**********************
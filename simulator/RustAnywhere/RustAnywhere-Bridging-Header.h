@@ -11,5 +11,6 @@
 
 // API
 #import "rust-anywhere.h"
+#import "display.h"
 
 #endif /* RustAnywhere_Bridging_Header_h */
@@ -39,7 +39,6 @@ class Simulator: NSObject {
     private var frameBuffer = [UInt32](repeating: 0, count: Int(LV_HOR_RES * LV_VER_RES))
     private var frameDirty = false
 
-    private var displayDriver = lv_disp_drv_t()
     private var mouseDriver = lv_indev_drv_t()
 
     private var lastMouseX: lv_coord_t = 0
@@ -61,11 +60,8 @@ class Simulator: NSObject {
         lv_init()
 
         // Register the display callbacks.
-        lv_disp_drv_init(&displayDriver)
-        displayDriver.disp_flush = displayFlush
-        displayDriver.disp_fill = displayFill
-        displayDriver.disp_map = displayMap
-        lv_disp_drv_register(&displayDriver)
+        var displayCallbacks = DisplayCallbacks(flush: displayFlush, fill: displayFill, map: displayMap)
+        display_register(&displayCallbacks)
 
         // Register the mouse callbacks.
         lv_indev_drv_init(&mouseDriver)